@@ -9,11 +9,13 @@ use {
         nonce_info::{NonceFull, NonceInfo},
         rent_collector::RentCollector,
         rent_debits::RentDebits,
+        secondary_index::SecondaryIndexScanStats,
         storable_accounts::StorableAccounts,
         transaction_results::TransactionExecutionResult,
     },
     dashmap::DashMap,
     log::*,
+    solana_measure::measure_us,
     solana_sdk::{
         account::{AccountSharedData, ReadableAccount},
         account_utils::StateMut,
@@ -97,6 +99,9 @@ pub struct Accounts {
     /// set of read-only and writable accounts which are currently
     /// being processed by banking/replay threads
     pub(crate) account_locks: Mutex<AccountLocks>,
+
+    /// secondary index scans, reported on an interval
+    secondary_index_scan_stats: SecondaryIndexScanStats,
 }
 
 // for the load instructions
@@ -122,6 +127,7 @@ impl Accounts {
         Self {
             accounts_db,
             account_locks: Mutex::new(AccountLocks::default()),
+            secondary_index_scan_stats: SecondaryIndexScanStats::default(),
         }
     }
 
@@ -426,7 +432,7 @@ impl Accounts {
         let sum = AtomicUsize::default();
         let config = config.recreate_with_abort();
         let mut collector = Vec::new();
-        let result = self
+        let (result, scan_us) = measure_us!(self
             .accounts_db
             .index_scan_accounts(
                 ancestors,
@@ -450,7 +456,14 @@ impl Accounts {
                 },
                 &config,
             )
-            .map(|_| collector);
+            .map(|_| collector));
+
+        self.secondary_index_scan_stats.record(
+            index_key,
+            scan_us,
+            result.as_ref().map(Vec::len).unwrap_or_default(),
+            config.is_aborted(),
+        );
         Self::maybe_abort_scan(result, &config)
     }
 
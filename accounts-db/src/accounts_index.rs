@@ -1990,6 +1990,10 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
         self.roots_tracker.read().unwrap().alive_roots.len()
     }
 
+    pub fn num_uncleaned_roots(&self) -> usize {
+        self.roots_tracker.read().unwrap().uncleaned_roots.len()
+    }
+
     pub fn all_alive_roots(&self) -> Vec<Slot> {
         let tracker = self.roots_tracker.read().unwrap();
         tracker.alive_roots.get_all()
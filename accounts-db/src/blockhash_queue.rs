@@ -76,6 +76,24 @@ impl BlockhashQueue {
             .map(|age| self.last_hash_index - age.hash_index)
     }
 
+    /// Age and registration timestamp (in milliseconds) of the oldest hash that is still
+    /// valid for the specified age
+    pub fn get_oldest_hash_valid_for_age(&self, max_age: usize) -> Option<(u64, u64)> {
+        self.ages
+            .values()
+            .filter(|age| Self::is_hash_index_valid(self.last_hash_index, max_age, age.hash_index))
+            .min_by_key(|age| age.hash_index)
+            .map(|age| (self.last_hash_index - age.hash_index, age.timestamp))
+    }
+
+    pub fn len(&self) -> usize {
+        self.ages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ages.is_empty()
+    }
+
     pub fn genesis_hash(&mut self, hash: &Hash, lamports_per_signature: u64) {
         self.ages.insert(
             *hash,
@@ -271,4 +289,26 @@ mod tests {
         assert!(hash_queue.is_hash_valid_for_age(&hash_list[MAX_AGE], 0));
         assert!(!hash_queue.is_hash_valid_for_age(&hash_list[MAX_AGE - 1], 0));
     }
+
+    #[test]
+    fn test_get_oldest_hash_valid_for_age() {
+        const MAX_AGE: usize = 10;
+        let mut hash_queue = BlockhashQueue::new(MAX_AGE);
+        assert!(hash_queue.is_empty());
+        assert!(hash_queue.get_oldest_hash_valid_for_age(MAX_AGE).is_none());
+
+        for _ in 0..MAX_AGE + 1 {
+            hash_queue.register_hash(&Hash::new_unique(), 0);
+        }
+        assert_eq!(hash_queue.len(), MAX_AGE + 1);
+
+        let (age, timestamp) = hash_queue.get_oldest_hash_valid_for_age(MAX_AGE).unwrap();
+        assert_eq!(age, MAX_AGE as u64);
+        assert!(timestamp > 0);
+
+        let (age, _) = hash_queue.get_oldest_hash_valid_for_age(3).unwrap();
+        assert_eq!(age, 3);
+        let (age, _) = hash_queue.get_oldest_hash_valid_for_age(0).unwrap();
+        assert_eq!(age, 0);
+    }
 }
@@ -1,17 +1,22 @@
 use {
+    crate::accounts_index::IndexKey,
     dashmap::{mapref::entry::Entry::Occupied, DashMap},
     log::*,
     solana_sdk::{pubkey::Pubkey, timing::AtomicInterval},
     std::{
-        collections::HashSet,
+        cmp::Reverse,
+        collections::{HashMap, HashSet},
         fmt::Debug,
         sync::{
             atomic::{AtomicU64, Ordering},
-            RwLock,
+            Mutex, RwLock,
         },
     },
 };
 
+const SECONDARY_INDEX_SCAN_STATS_REPORT_INTERVAL_MS: u64 = 10_000;
+const SECONDARY_INDEX_SCAN_STATS_NUM_TOP_KEYS: usize = 5;
+
 // The only cases where an inner key should map to a different outer key is
 // if the key had different account data for the indexed key across different
 // slots. As this is rare, it should be ok to use a Vec here over a HashSet, even
@@ -33,6 +38,117 @@ pub struct SecondaryIndexStats {
     num_inner_keys: AtomicU64,
 }
 
+#[derive(Debug, Default)]
+struct SecondaryIndexScanCounts {
+    num_scans: AtomicU64,
+    scan_us: AtomicU64,
+    max_scan_us: AtomicU64,
+    num_results: AtomicU64,
+    num_aborted: AtomicU64,
+}
+
+/// Aggregates secondary index scans (getProgramAccounts, getTokenAccountsBy*)
+/// and reports them once per interval, along with the keys that took the most
+/// scan time in that interval.
+#[derive(Debug, Default)]
+pub struct SecondaryIndexScanStats {
+    last_report: AtomicInterval,
+    program_id: SecondaryIndexScanCounts,
+    spl_token_mint: SecondaryIndexScanCounts,
+    spl_token_owner: SecondaryIndexScanCounts,
+    // (index, key) -> (total scan us, number of scans) since the last report
+    scan_us_by_key: Mutex<HashMap<(&'static str, Pubkey), (u64, u64)>>,
+}
+
+impl SecondaryIndexScanStats {
+    pub fn record(&self, index_key: &IndexKey, scan_us: u64, num_results: usize, aborted: bool) {
+        let (index, counts, key) = match index_key {
+            IndexKey::ProgramId(key) => ("program_id", &self.program_id, key),
+            IndexKey::SplTokenMint(key) => ("spl_token_mint", &self.spl_token_mint, key),
+            IndexKey::SplTokenOwner(key) => ("spl_token_owner", &self.spl_token_owner, key),
+        };
+        counts.num_scans.fetch_add(1, Ordering::Relaxed);
+        counts.scan_us.fetch_add(scan_us, Ordering::Relaxed);
+        counts.max_scan_us.fetch_max(scan_us, Ordering::Relaxed);
+        counts
+            .num_results
+            .fetch_add(num_results as u64, Ordering::Relaxed);
+        if aborted {
+            counts.num_aborted.fetch_add(1, Ordering::Relaxed);
+        }
+        {
+            let mut scan_us_by_key = self.scan_us_by_key.lock().unwrap();
+            let (key_scan_us, key_num_scans) = scan_us_by_key.entry((index, *key)).or_default();
+            *key_scan_us += scan_us;
+            *key_num_scans += 1;
+        }
+
+        if self
+            .last_report
+            .should_update(SECONDARY_INDEX_SCAN_STATS_REPORT_INTERVAL_MS)
+        {
+            self.report();
+        }
+    }
+
+    fn report(&self) {
+        for (index, counts) in [
+            ("program_id", &self.program_id),
+            ("spl_token_mint", &self.spl_token_mint),
+            ("spl_token_owner", &self.spl_token_owner),
+        ] {
+            let num_scans = counts.num_scans.swap(0, Ordering::Relaxed);
+            if num_scans == 0 {
+                continue;
+            }
+            datapoint_info!(
+                "accounts-secondary_index_scan",
+                ("index", index, String),
+                ("num_scans", num_scans, i64),
+                ("scan_us", counts.scan_us.swap(0, Ordering::Relaxed), i64),
+                (
+                    "max_scan_us",
+                    counts.max_scan_us.swap(0, Ordering::Relaxed),
+                    i64
+                ),
+                (
+                    "num_results",
+                    counts.num_results.swap(0, Ordering::Relaxed),
+                    i64
+                ),
+                (
+                    "num_aborted",
+                    counts.num_aborted.swap(0, Ordering::Relaxed),
+                    i64
+                ),
+            );
+        }
+
+        let scan_us_by_key = std::mem::take(&mut *self.scan_us_by_key.lock().unwrap());
+        for (rank, ((index, key), (scan_us, num_scans))) in
+            Self::top_keys(scan_us_by_key).into_iter().enumerate()
+        {
+            datapoint_info!(
+                "accounts-secondary_index_scan_top_keys",
+                ("rank", rank, i64),
+                ("index", index, String),
+                ("key", key.to_string(), String),
+                ("scan_us", scan_us, i64),
+                ("num_scans", num_scans, i64),
+            );
+        }
+    }
+
+    fn top_keys(
+        scan_us_by_key: HashMap<(&'static str, Pubkey), (u64, u64)>,
+    ) -> Vec<((&'static str, Pubkey), (u64, u64))> {
+        let mut top_keys: Vec<_> = scan_us_by_key.into_iter().collect();
+        top_keys.sort_unstable_by_key(|(_, (scan_us, _))| Reverse(*scan_us));
+        top_keys.truncate(SECONDARY_INDEX_SCAN_STATS_NUM_TOP_KEYS);
+        top_keys
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DashMapSecondaryIndexEntry {
     account_keys: DashMap<Pubkey, ()>,
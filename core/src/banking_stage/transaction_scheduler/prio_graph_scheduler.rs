@@ -13,6 +13,7 @@ use {
         transaction_scheduler::transaction_priority_id::TransactionPriorityId,
     },
     crossbeam_channel::{Receiver, Sender, TryRecvError},
+    histogram::Histogram,
     itertools::izip,
     prio_graph::{AccessKind, PrioGraph},
     solana_measure::measure_us,
@@ -22,12 +23,17 @@ use {
     },
 };
 
+/// Upper bound on recorded time in queue; longer waits are recorded as this value.
+const MAX_TIME_IN_QUEUE_US: u64 = 60_000_000;
+
 pub(crate) struct PrioGraphScheduler {
     in_flight_tracker: InFlightTracker,
     account_locks: ThreadAwareAccountLocks,
     consume_work_senders: Vec<Sender<ConsumeWork>>,
     finished_consume_work_receiver: Receiver<FinishedConsumeWork>,
     look_ahead_window_size: usize,
+    /// Time transactions spent in the container before being scheduled.
+    time_in_queue_us: Histogram,
 }
 
 impl PrioGraphScheduler {
@@ -42,9 +48,19 @@ impl PrioGraphScheduler {
             consume_work_senders,
             finished_consume_work_receiver,
             look_ahead_window_size: 2048,
+            time_in_queue_us: Histogram::configure()
+                .max_value(MAX_TIME_IN_QUEUE_US)
+                .build()
+                .unwrap(),
         }
     }
 
+    /// Time transactions spent in the container before being scheduled, since
+    /// the histogram was last cleared.
+    pub(crate) fn time_in_queue_us(&mut self) -> &mut Histogram {
+        &mut self.time_in_queue_us
+    }
+
     /// Schedule transactions from the given `TransactionStateContainer` to be
     /// consumed by the worker threads. Returns summary of scheduling, or an
     /// error.
@@ -139,6 +155,7 @@ impl PrioGraphScheduler {
         let mut num_scheduled: usize = 0;
         let mut num_sent: usize = 0;
         let mut num_unschedulable: usize = 0;
+        let mut num_account_lock_conflicts: usize = 0;
         while num_scheduled < MAX_TRANSACTIONS_PER_SCHEDULING_PASS {
             // If nothing is in the main-queue of the `PrioGraph` then there's nothing left to schedule.
             if prio_graph.is_empty() {
@@ -185,10 +202,15 @@ impl PrioGraphScheduler {
                     blocking_locks.take_locks(transaction.message());
                     unschedulable_ids.push(id);
                     saturating_add_assign!(num_unschedulable, 1);
+                    saturating_add_assign!(num_account_lock_conflicts, 1);
                     continue;
                 };
 
                 saturating_add_assign!(num_scheduled, 1);
+                let time_in_queue_us = transaction_state.received_time().elapsed().as_micros();
+                let _ = self
+                    .time_in_queue_us
+                    .increment(time_in_queue_us.min(u128::from(MAX_TIME_IN_QUEUE_US)) as u64);
 
                 let sanitized_transaction_ttl = transaction_state.transition_to_pending();
                 let cost = transaction_state.transaction_cost().sum();
@@ -247,6 +269,7 @@ impl PrioGraphScheduler {
         Ok(SchedulingSummary {
             num_scheduled,
             num_unschedulable,
+            num_account_lock_conflicts,
             num_filtered_out,
             filter_time_us: total_filter_time_us,
         })
@@ -433,6 +456,9 @@ pub(crate) struct SchedulingSummary {
     pub num_scheduled: usize,
     /// Number of transactions that were not scheduled due to conflicts.
     pub num_unschedulable: usize,
+    /// Number of unschedulable transactions whose account locks conflicted
+    /// with transactions in-flight on other threads.
+    pub num_account_lock_conflicts: usize,
     /// Number of transactions that were dropped due to filter.
     pub num_filtered_out: usize,
     /// Time spent filtering transactions
@@ -732,6 +758,8 @@ mod tests {
             .unwrap();
         assert_eq!(scheduling_summary.num_scheduled, 4);
         assert_eq!(scheduling_summary.num_unschedulable, 2);
+        assert_eq!(scheduling_summary.num_account_lock_conflicts, 1);
+        assert_eq!(scheduler.time_in_queue_us().entries(), 4);
         let (thread_0_work, thread_0_ids) = collect_work(&work_receivers[0]);
         assert_eq!(thread_0_ids, [txids!([0]), txids!([2])]);
         assert_eq!(
@@ -745,6 +773,7 @@ mod tests {
             .unwrap();
         assert_eq!(scheduling_summary.num_scheduled, 0);
         assert_eq!(scheduling_summary.num_unschedulable, 2);
+        assert_eq!(scheduling_summary.num_account_lock_conflicts, 1);
 
         // Complete batch on thread 0. Remaining txs can be scheduled onto thread 1
         finished_work_sender
@@ -3,10 +3,11 @@
 
 use {
     super::{
-        prio_graph_scheduler::PrioGraphScheduler, scheduler_error::SchedulerError,
+        prio_graph_scheduler::PrioGraphScheduler,
+        scheduler_error::SchedulerError,
         transaction_id_generator::TransactionIdGenerator,
         transaction_state::SanitizedTransactionTTL,
-        transaction_state_container::TransactionStateContainer,
+        transaction_state_container::{TransactionStateContainer, PRIORITY_BAND_LOWER_BOUNDS},
     },
     crate::banking_stage::{
         consume_worker::ConsumeWorkerMetrics,
@@ -17,6 +18,7 @@ use {
         TOTAL_BUFFERED_PACKETS,
     },
     crossbeam_channel::RecvTimeoutError,
+    histogram::Histogram,
     solana_accounts_db::transaction_error_metrics::TransactionErrorMetrics,
     solana_cost_model::cost_model::CostModel,
     solana_measure::measure_us,
@@ -99,8 +101,10 @@ impl SchedulerController {
             // Report metrics only if there is data.
             // Reset intervals when appropriate, regardless of report.
             let should_report = self.count_metrics.has_data();
-            self.count_metrics.maybe_report_and_reset(should_report);
-            self.timing_metrics.maybe_report_and_reset(should_report);
+            self.count_metrics
+                .maybe_report_and_reset(should_report, &self.container);
+            self.timing_metrics
+                .maybe_report_and_reset(should_report, self.scheduler.time_in_queue_us());
             self.worker_metrics
                 .iter()
                 .for_each(|metrics| metrics.maybe_report_and_reset());
@@ -131,6 +135,10 @@ impl SchedulerController {
                     self.count_metrics.num_unschedulable,
                     scheduling_summary.num_unschedulable
                 );
+                saturating_add_assign!(
+                    self.count_metrics.num_account_lock_conflicts,
+                    scheduling_summary.num_account_lock_conflicts
+                );
                 saturating_add_assign!(
                     self.count_metrics.num_schedule_filtered_out,
                     scheduling_summary.num_filtered_out
@@ -397,6 +405,9 @@ struct SchedulerCountMetrics {
     num_scheduled: usize,
     /// Number of transactions that were unschedulable.
     num_unschedulable: usize,
+    /// Number of unschedulable transactions that conflicted with account locks
+    /// held by other threads.
+    num_account_lock_conflicts: usize,
     /// Number of transactions that were filtered out during scheduling.
     num_schedule_filtered_out: usize,
     /// Number of completed transactions received from workers.
@@ -422,23 +433,42 @@ struct SchedulerCountMetrics {
 }
 
 impl SchedulerCountMetrics {
-    fn maybe_report_and_reset(&mut self, should_report: bool) {
+    fn maybe_report_and_reset(
+        &mut self,
+        should_report: bool,
+        container: &TransactionStateContainer,
+    ) {
         const REPORT_INTERVAL_MS: u64 = 1000;
         if self.interval.should_update(REPORT_INTERVAL_MS) {
             if should_report {
-                self.report();
+                self.report(container);
             }
             self.reset();
         }
     }
 
-    fn report(&self) {
+    fn report(&self, container: &TransactionStateContainer) {
+        for (lower_bound, queue_len) in PRIORITY_BAND_LOWER_BOUNDS
+            .iter()
+            .zip(container.queue_len_by_priority_band())
+        {
+            datapoint_info!(
+                "banking_stage_scheduler_priority_band",
+                ("priority_lower_bound", *lower_bound, i64),
+                ("queue_len", queue_len, i64),
+            );
+        }
         datapoint_info!(
             "banking_stage_scheduler_counts",
             ("num_received", self.num_received, i64),
             ("num_buffered", self.num_buffered, i64),
             ("num_scheduled", self.num_scheduled, i64),
             ("num_unschedulable", self.num_unschedulable, i64),
+            (
+                "num_account_lock_conflicts",
+                self.num_account_lock_conflicts,
+                i64
+            ),
             (
                 "num_schedule_filtered_out",
                 self.num_schedule_filtered_out,
@@ -477,6 +507,7 @@ impl SchedulerCountMetrics {
             || self.num_buffered != 0
             || self.num_scheduled != 0
             || self.num_unschedulable != 0
+            || self.num_account_lock_conflicts != 0
             || self.num_schedule_filtered_out != 0
             || self.num_finished != 0
             || self.num_retryable != 0
@@ -494,6 +525,7 @@ impl SchedulerCountMetrics {
         self.num_buffered = 0;
         self.num_scheduled = 0;
         self.num_unschedulable = 0;
+        self.num_account_lock_conflicts = 0;
         self.num_schedule_filtered_out = 0;
         self.num_finished = 0;
         self.num_retryable = 0;
@@ -529,17 +561,18 @@ struct SchedulerTimingMetrics {
 }
 
 impl SchedulerTimingMetrics {
-    fn maybe_report_and_reset(&mut self, should_report: bool) {
+    fn maybe_report_and_reset(&mut self, should_report: bool, time_in_queue_us: &mut Histogram) {
         const REPORT_INTERVAL_MS: u64 = 1000;
         if self.interval.should_update(REPORT_INTERVAL_MS) {
             if should_report {
-                self.report();
+                self.report(time_in_queue_us);
             }
             self.reset();
+            time_in_queue_us.clear();
         }
     }
 
-    fn report(&self) {
+    fn report(&self, time_in_queue_us: &Histogram) {
         datapoint_info!(
             "banking_stage_scheduler_timing",
             ("decision_time_us", self.decision_time_us, i64),
@@ -553,6 +586,26 @@ impl SchedulerTimingMetrics {
                 "receive_completed_time_us",
                 self.receive_completed_time_us,
                 i64
+            ),
+            (
+                "time_in_queue_us_50pct",
+                time_in_queue_us.percentile(50.0).unwrap_or(0),
+                i64
+            ),
+            (
+                "time_in_queue_us_90pct",
+                time_in_queue_us.percentile(90.0).unwrap_or(0),
+                i64
+            ),
+            (
+                "time_in_queue_us_99pct",
+                time_in_queue_us.percentile(99.0).unwrap_or(0),
+                i64
+            ),
+            (
+                "time_in_queue_us_max",
+                time_in_queue_us.maximum().unwrap_or(0),
+                i64
             )
         );
     }
@@ -2,6 +2,7 @@ use {
     solana_cost_model::transaction_cost::TransactionCost,
     solana_runtime::transaction_priority_details::TransactionPriorityDetails,
     solana_sdk::{slot_history::Slot, transaction::SanitizedTransaction},
+    std::time::Instant,
 };
 
 /// Simple wrapper type to tie a sanitized transaction to max age slot.
@@ -37,12 +38,14 @@ pub(crate) enum TransactionState {
         transaction_priority_details: TransactionPriorityDetails,
         transaction_cost: TransactionCost,
         forwarded: bool,
+        received_time: Instant,
     },
     /// The transaction is currently scheduled or being processed.
     Pending {
         transaction_priority_details: TransactionPriorityDetails,
         transaction_cost: TransactionCost,
         forwarded: bool,
+        received_time: Instant,
     },
 }
 
@@ -58,6 +61,7 @@ impl TransactionState {
             transaction_priority_details,
             transaction_cost,
             forwarded: false,
+            received_time: Instant::now(),
         }
     }
 
@@ -100,6 +104,14 @@ impl TransactionState {
         }
     }
 
+    /// Returns when the transaction was first inserted into the scheduler.
+    pub(crate) fn received_time(&self) -> Instant {
+        match self {
+            Self::Unprocessed { received_time, .. } => *received_time,
+            Self::Pending { received_time, .. } => *received_time,
+        }
+    }
+
     /// Sets the transaction as forwarded.
     pub(crate) fn set_forwarded(&mut self) {
         match self {
@@ -122,11 +134,13 @@ impl TransactionState {
                 transaction_priority_details,
                 transaction_cost,
                 forwarded,
+                received_time,
             } => {
                 *self = TransactionState::Pending {
                     transaction_priority_details,
                     transaction_cost,
                     forwarded,
+                    received_time,
                 };
                 transaction_ttl
             }
@@ -149,12 +163,14 @@ impl TransactionState {
                 transaction_priority_details,
                 transaction_cost,
                 forwarded,
+                received_time,
             } => {
                 *self = Self::Unprocessed {
                     transaction_ttl,
                     transaction_priority_details,
                     transaction_cost,
                     forwarded,
+                    received_time,
                 }
             }
         }
@@ -187,6 +203,7 @@ impl TransactionState {
                     writable_accounts: vec![],
                 },
                 forwarded: false,
+                received_time: Instant::now(),
             },
         )
     }
@@ -10,6 +10,10 @@ use {
     std::collections::HashMap,
 };
 
+/// Inclusive lower bounds, in micro-lamports per compute unit, of the priority
+/// bands queued transactions are grouped into for metrics.
+pub(crate) const PRIORITY_BAND_LOWER_BOUNDS: [u64; 4] = [0, 1, 10_000, 1_000_000];
+
 /// This structure will hold `TransactionState` for the entirety of a
 /// transaction's lifetime in the scheduler and BankingStage as a whole.
 ///
@@ -38,6 +42,9 @@ use {
 pub(crate) struct TransactionStateContainer {
     priority_queue: MinMaxHeap<TransactionPriorityId>,
     id_to_transaction_state: HashMap<TransactionId, TransactionState>,
+    /// Number of queued transactions in each band of `PRIORITY_BAND_LOWER_BOUNDS`,
+    /// kept up to date as ids are pushed into and popped from the queue.
+    queue_len_by_priority_band: [usize; PRIORITY_BAND_LOWER_BOUNDS.len()],
 }
 
 impl TransactionStateContainer {
@@ -45,6 +52,7 @@ impl TransactionStateContainer {
         Self {
             priority_queue: MinMaxHeap::with_capacity(capacity),
             id_to_transaction_state: HashMap::with_capacity(capacity),
+            queue_len_by_priority_band: [0; PRIORITY_BAND_LOWER_BOUNDS.len()],
         }
     }
 
@@ -58,9 +66,17 @@ impl TransactionStateContainer {
         self.priority_queue.capacity() - self.priority_queue.len()
     }
 
+    /// Returns the number of queued transactions in each band of
+    /// `PRIORITY_BAND_LOWER_BOUNDS`.
+    pub(crate) fn queue_len_by_priority_band(&self) -> [usize; PRIORITY_BAND_LOWER_BOUNDS.len()] {
+        self.queue_len_by_priority_band
+    }
+
     /// Get the top transaction id in the priority queue.
     pub(crate) fn pop(&mut self) -> Option<TransactionPriorityId> {
-        self.priority_queue.pop_max()
+        let priority_id = self.priority_queue.pop_max()?;
+        self.queue_len_by_priority_band[priority_band(priority_id.priority)] -= 1;
+        Some(priority_id)
     }
 
     /// Get mutable transaction state by id.
@@ -133,8 +149,10 @@ impl TransactionStateContainer {
     /// transaction will be dropped (removed from the queue and map).
     /// Returns `true` if a packet was dropped due to capacity limits.
     pub(crate) fn push_id_into_queue(&mut self, priority_id: TransactionPriorityId) -> bool {
+        self.queue_len_by_priority_band[priority_band(priority_id.priority)] += 1;
         if self.remaining_queue_capacity() == 0 {
             let popped_id = self.priority_queue.push_pop_min(priority_id);
+            self.queue_len_by_priority_band[priority_band(popped_id.priority)] -= 1;
             self.remove_by_id(&popped_id.id);
             true
         } else {
@@ -151,6 +169,14 @@ impl TransactionStateContainer {
     }
 }
 
+/// Returns the index of the band of `PRIORITY_BAND_LOWER_BOUNDS` that `priority` falls into.
+fn priority_band(priority: u64) -> usize {
+    PRIORITY_BAND_LOWER_BOUNDS
+        .iter()
+        .rposition(|lower_bound| priority >= *lower_bound)
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -247,6 +273,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_queue_len_by_priority_band() {
+        let mut container = TransactionStateContainer::with_capacity(5);
+        assert_eq!(container.queue_len_by_priority_band(), [0, 0, 0, 0]);
+
+        for (id, priority) in [0, 1, 9_999, 10_000, 2_000_000].into_iter().enumerate() {
+            let (transaction_ttl, transaction_priority_details, transaction_cost) =
+                test_transaction(priority);
+            container.insert_new_transaction(
+                TransactionId::new(id as u64),
+                transaction_ttl,
+                transaction_priority_details,
+                transaction_cost,
+            );
+        }
+        assert_eq!(container.queue_len_by_priority_band(), [1, 2, 1, 1]);
+
+        // popped transactions are no longer queued
+        container.pop();
+        assert_eq!(container.queue_len_by_priority_band(), [1, 2, 1, 0]);
+
+        // the lowest priority transaction is dropped once the queue is full
+        for (id, priority) in [(5, 5), (6, 20_000)] {
+            let (transaction_ttl, transaction_priority_details, transaction_cost) =
+                test_transaction(priority);
+            container.insert_new_transaction(
+                TransactionId::new(id),
+                transaction_ttl,
+                transaction_priority_details,
+                transaction_cost,
+            );
+        }
+        assert_eq!(container.queue_len_by_priority_band(), [0, 3, 2, 0]);
+    }
+
     #[test]
     fn test_get_mut_transaction_state() {
         let mut container = TransactionStateContainer::with_capacity(5);
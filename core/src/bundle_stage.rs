@@ -44,6 +44,7 @@ mod bundle_packet_receiver;
 mod bundle_reserved_space_manager;
 pub(crate) mod bundle_stage_leader_metrics;
 mod committer;
+mod tip_revenue_metrics;
 
 const MAX_BUNDLE_RETRY_DURATION: Duration = Duration::from_millis(10);
 const SLOT_BOUNDARY_CHECK_PERIOD: Duration = Duration::from_millis(10);
@@ -390,6 +391,10 @@ impl BundleStage {
             .leader_slot_metrics_tracker()
             .increment_make_decision_us(make_decision_time.as_us());
 
+        // Our leader bank is frozen after the last Consume decision for it, so this is checked
+        // whatever the decision
+        consumer.maybe_report_tip_revenue();
+
         match decision {
             // BufferedPacketsDecision::Consume means this leader is scheduled to be running at the moment.
             // Execute, record, and commit as many bundles possible given time, compute, and other constraints.
@@ -10,6 +10,7 @@ use {
             bundle_reserved_space_manager::BundleReservedSpaceManager,
             bundle_stage_leader_metrics::BundleStageLeaderMetrics,
             committer::Committer,
+            tip_revenue_metrics::TipRevenueMetrics,
         },
         consensus_cache_updater::ConsensusCacheUpdater,
         immutable_deserialized_bundle::ImmutableDeserializedBundle,
@@ -58,6 +59,7 @@ pub struct BundleConsumer {
 
     tip_manager: TipManager,
     last_tip_update_slot: Slot,
+    tip_revenue_metrics: TipRevenueMetrics,
 
     blacklisted_accounts: HashSet<Pubkey>,
 
@@ -97,6 +99,7 @@ impl BundleConsumer {
             tip_manager,
             // MAX because sending tips during slot 0 in tests doesn't work
             last_tip_update_slot: u64::MAX,
+            tip_revenue_metrics: TipRevenueMetrics::default(),
             blacklisted_accounts: HashSet::default(),
             bundle_account_locker,
             block_builder_fee_info,
@@ -131,6 +134,8 @@ impl BundleConsumer {
     ) {
         self.maybe_update_blacklist(bank_start);
         self.reserved_space.tick(&bank_start.working_bank);
+        self.tip_revenue_metrics
+            .observe_leader_bank(&bank_start.working_bank, &self.tip_manager);
 
         let reached_end_of_slot = unprocessed_transaction_storage.process_bundles(
             bank_start.working_bank.clone(),
@@ -165,6 +170,12 @@ impl BundleConsumer {
         }
     }
 
+    /// Attributes the tips of our last leader slot once its bank is frozen
+    pub fn maybe_report_tip_revenue(&mut self) {
+        self.tip_revenue_metrics
+            .attribute_frozen_leader_slot(&self.tip_manager);
+    }
+
     /// Blacklist is updated with the tip payment program + any consensus accounts.
     fn maybe_update_blacklist(&mut self, bank_start: &BankStart) {
         if self
@@ -0,0 +1,208 @@
+//! Attributes the tips paid into the tip payment accounts during this validator's leader slots
+//! to the tip account they were paid to, accumulated per epoch.
+
+use {
+    crate::tip_manager::TipManager,
+    solana_runtime::bank::Bank,
+    solana_sdk::{
+        account::ReadableAccount,
+        clock::{Epoch, Slot},
+        pubkey::Pubkey,
+    },
+    std::{collections::HashMap, sync::Arc},
+};
+
+#[derive(Default)]
+pub(crate) struct TipRevenueMetrics {
+    /// Our most recent leader bank, until its tips are attributed once it is frozen
+    pending_slot: Option<PendingLeaderSlot>,
+    epoch: Epoch,
+    tips_by_account: HashMap<Pubkey, u64>,
+}
+
+/// A leader bank and what is needed from its parent to attribute its tips, recorded when the bank
+/// is first seen since the parent may be squashed away by the time the bank is frozen
+struct PendingLeaderSlot {
+    bank: Arc<Bank>,
+    parent_tip_config: Option<(Pubkey, Pubkey)>,
+    parent_balances: Vec<(Pubkey, u64)>,
+}
+
+impl TipRevenueMetrics {
+    pub(crate) fn observe_leader_bank(&mut self, bank: &Arc<Bank>, tip_manager: &TipManager) {
+        if self
+            .pending_slot
+            .as_ref()
+            .is_some_and(|pending_slot| pending_slot.bank.slot() == bank.slot())
+        {
+            return;
+        }
+        self.attribute_frozen_leader_slot(tip_manager);
+        if let Some(pending_slot) = self.pending_slot.take() {
+            warn!(
+                "tips of leader slot {} not attributed, the bank was not frozen",
+                pending_slot.bank.slot()
+            );
+        }
+        let Some(parent) = bank.parent() else {
+            return;
+        };
+        self.pending_slot = Some(PendingLeaderSlot {
+            bank: bank.clone(),
+            parent_tip_config: tip_config(&parent, tip_manager),
+            parent_balances: tip_manager
+                .get_tip_accounts()
+                .into_iter()
+                .map(|tip_account| (tip_account, parent.get_balance(&tip_account)))
+                .collect(),
+        });
+    }
+
+    /// Attributes the tips of the pending leader bank once it is frozen, since no more
+    /// transactions are committed to it from then on. Called on every bundle stage iteration,
+    /// since the bank is no longer handed to bundle stage after its last tick.
+    pub(crate) fn attribute_frozen_leader_slot(&mut self, tip_manager: &TipManager) {
+        if !self
+            .pending_slot
+            .as_ref()
+            .is_some_and(|pending_slot| pending_slot.bank.is_frozen())
+        {
+            return;
+        }
+        let Some(pending_slot) = self.pending_slot.take() else {
+            return;
+        };
+        let bank = pending_slot.bank;
+        // The tip crank moves all tips collected so far to the previous tip receiver, and any
+        // block builder commission to the previous block builder, so when it ran in this bank
+        // only the balance above rent exemption was tipped to us
+        let cranked = pending_slot.parent_tip_config != tip_config(&bank, tip_manager);
+
+        let slot_tips = pending_slot
+            .parent_balances
+            .into_iter()
+            .map(|(tip_account, parent_balance)| {
+                let balance = bank.get_balance(&tip_account);
+                let baseline = if cranked {
+                    let data_len = bank
+                        .get_account(&tip_account)
+                        .map(|account| account.data().len())
+                        .unwrap_or_default();
+                    bank.get_minimum_balance_for_rent_exemption(data_len)
+                } else {
+                    parent_balance
+                };
+                (tip_account, balance.saturating_sub(baseline))
+            })
+            .collect::<Vec<_>>();
+
+        self.accumulate(bank.epoch(), &slot_tips);
+        self.report(bank.slot(), &slot_tips);
+    }
+
+    fn accumulate(&mut self, epoch: Epoch, slot_tips: &[(Pubkey, u64)]) {
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.tips_by_account.clear();
+        }
+        for (tip_account, tips) in slot_tips {
+            let epoch_tips = self.tips_by_account.entry(*tip_account).or_default();
+            *epoch_tips = epoch_tips.saturating_add(*tips);
+        }
+    }
+
+    fn report(&self, slot: Slot, slot_tips: &[(Pubkey, u64)]) {
+        for (tip_account, tips) in slot_tips {
+            let epoch_tips = self
+                .tips_by_account
+                .get(tip_account)
+                .copied()
+                .unwrap_or_default();
+            if epoch_tips == 0 {
+                continue;
+            }
+            datapoint_info!(
+                "bundle_stage-tip_revenue",
+                ("epoch", self.epoch, i64),
+                ("slot", slot, i64),
+                ("tip_account", tip_account.to_string(), String),
+                ("slot_tips", *tips, i64),
+                ("epoch_tips", epoch_tips, i64),
+            );
+        }
+    }
+}
+
+/// The tip receiver and block builder configured in the tip payment program, which both change
+/// when the tip crank runs
+fn tip_config(bank: &Bank, tip_manager: &TipManager) -> Option<(Pubkey, Pubkey)> {
+    let config = tip_manager.get_tip_payment_config_account(bank).ok()?;
+    Some((config.tip_receiver, config.block_builder))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::tip_manager::{TipDistributionAccountConfig, TipManagerConfig},
+        solana_runtime::genesis_utils::create_genesis_config,
+        solana_sdk::{account::AccountSharedData, system_program},
+    };
+
+    #[test]
+    fn test_attribute_frozen_leader_slot() {
+        let tip_manager = TipManager::new(TipManagerConfig {
+            tip_payment_program_id: Pubkey::new_unique(),
+            tip_distribution_program_id: Pubkey::new_unique(),
+            tip_distribution_account_config: TipDistributionAccountConfig {
+                merkle_root_upload_authority: Pubkey::new_unique(),
+                vote_account: Pubkey::new_unique(),
+                commission_bps: 10,
+            },
+        });
+        let tip_account = *tip_manager.get_tip_accounts().iter().next().unwrap();
+        let parent = Arc::new(Bank::new_for_tests(
+            &create_genesis_config(1_000_000_000).genesis_config,
+        ));
+        parent.store_account(
+            &tip_account,
+            &AccountSharedData::new(100, 0, &system_program::id()),
+        );
+        let bank = Arc::new(Bank::new_from_parent(parent, &Pubkey::new_unique(), 1));
+        let mut metrics = TipRevenueMetrics::default();
+
+        metrics.observe_leader_bank(&bank, &tip_manager);
+        bank.store_account(
+            &tip_account,
+            &AccountSharedData::new(150, 0, &system_program::id()),
+        );
+        // Nothing is attributed while transactions may still be committed to the bank
+        metrics.attribute_frozen_leader_slot(&tip_manager);
+        assert!(metrics.pending_slot.is_some());
+        assert!(metrics.tips_by_account.is_empty());
+
+        // The slot ends without bundle stage seeing the bank again
+        bank.freeze();
+        metrics.attribute_frozen_leader_slot(&tip_manager);
+        assert!(metrics.pending_slot.is_none());
+        assert_eq!(metrics.tips_by_account[&tip_account], 50);
+    }
+
+    #[test]
+    fn test_accumulate_resets_on_new_epoch() {
+        let tip_account_0 = Pubkey::new_unique();
+        let tip_account_1 = Pubkey::new_unique();
+        let mut metrics = TipRevenueMetrics::default();
+
+        metrics.accumulate(3, &[(tip_account_0, 10), (tip_account_1, 0)]);
+        metrics.accumulate(3, &[(tip_account_0, 5), (tip_account_1, 7)]);
+        assert_eq!(metrics.epoch, 3);
+        assert_eq!(metrics.tips_by_account[&tip_account_0], 15);
+        assert_eq!(metrics.tips_by_account[&tip_account_1], 7);
+
+        metrics.accumulate(4, &[(tip_account_1, 1)]);
+        assert_eq!(metrics.epoch, 4);
+        assert_eq!(metrics.tips_by_account.get(&tip_account_0), None);
+        assert_eq!(metrics.tips_by_account[&tip_account_1], 1);
+    }
+}
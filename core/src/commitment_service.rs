@@ -8,7 +8,11 @@ use {
         bank::Bank,
         commitment::{BlockCommitment, BlockCommitmentCache, CommitmentSlots, VOTE_THRESHOLD_SIZE},
     },
-    solana_sdk::clock::Slot,
+    solana_sdk::{
+        clock::{Epoch, Slot},
+        epoch_schedule::EpochSchedule,
+        timing::timestamp,
+    },
     solana_vote_program::vote_state::VoteState,
     std::{
         cmp::max,
@@ -50,6 +54,51 @@ fn get_highest_super_majority_root(mut rooted_stake: Vec<(Slot, u64)>, total_sta
     0
 }
 
+/// Reported once the cluster makes its first supermajority root in a new epoch
+#[derive(Debug, PartialEq, Eq)]
+struct EpochSuperMajorityRoot {
+    epoch: Epoch,
+    highest_super_majority_root: Slot,
+    // How far our own root trails `highest_super_majority_root`
+    root_lag: u64,
+}
+
+/// Finds the first supermajority root the cluster makes in each new epoch, to measure how long
+/// after the epoch boundary it is reached.
+#[derive(Default)]
+struct EpochSuperMajorityRootTracker {
+    super_majority_root_epoch: Option<Epoch>,
+}
+
+impl EpochSuperMajorityRootTracker {
+    fn update(
+        &mut self,
+        epoch_schedule: &EpochSchedule,
+        bank_epoch: Epoch,
+        root: Slot,
+        highest_super_majority_root: Slot,
+    ) -> Option<EpochSuperMajorityRoot> {
+        let super_majority_root_epoch = epoch_schedule.get_epoch(highest_super_majority_root);
+        let Some(last_super_majority_root_epoch) = self.super_majority_root_epoch else {
+            // Nothing is reported for the epoch we started in
+            self.super_majority_root_epoch = Some(super_majority_root_epoch);
+            return None;
+        };
+        if super_majority_root_epoch <= last_super_majority_root_epoch {
+            return None;
+        }
+        self.super_majority_root_epoch = Some(super_majority_root_epoch);
+
+        // The start of the epoch is read from the bank's clock, which only has it for the bank's
+        // own epoch
+        (bank_epoch == super_majority_root_epoch).then_some(EpochSuperMajorityRoot {
+            epoch: super_majority_root_epoch,
+            highest_super_majority_root,
+            root_lag: highest_super_majority_root.saturating_sub(root),
+        })
+    }
+}
+
 pub struct AggregateCommitmentService {
     t_commitment: JoinHandle<()>,
 }
@@ -69,15 +118,23 @@ impl AggregateCommitmentService {
             Self {
                 t_commitment: Builder::new()
                     .name("solAggCommitSvc".to_string())
-                    .spawn(move || loop {
-                        if exit.load(Ordering::Relaxed) {
-                            break;
-                        }
-
-                        if let Err(RecvTimeoutError::Disconnected) =
-                            Self::run(&receiver, &block_commitment_cache, &subscriptions, &exit)
-                        {
-                            break;
+                    .spawn(move || {
+                        let mut epoch_super_majority_root_tracker =
+                            EpochSuperMajorityRootTracker::default();
+                        loop {
+                            if exit.load(Ordering::Relaxed) {
+                                break;
+                            }
+
+                            if let Err(RecvTimeoutError::Disconnected) = Self::run(
+                                &receiver,
+                                &block_commitment_cache,
+                                &subscriptions,
+                                &mut epoch_super_majority_root_tracker,
+                                &exit,
+                            ) {
+                                break;
+                            }
                         }
                     })
                     .unwrap(),
@@ -89,6 +146,7 @@ impl AggregateCommitmentService {
         receiver: &Receiver<CommitmentAggregationData>,
         block_commitment_cache: &RwLock<BlockCommitmentCache>,
         subscriptions: &Arc<RpcSubscriptions>,
+        epoch_super_majority_root_tracker: &mut EpochSuperMajorityRootTracker,
         exit: &AtomicBool,
     ) -> Result<(), RecvTimeoutError> {
         loop {
@@ -104,6 +162,7 @@ impl AggregateCommitmentService {
                 continue;
             }
 
+            let bank = aggregation_data.bank.clone();
             let mut aggregate_commitment_time = Measure::start("aggregate-commitment-ms");
             let update_commitment_slots =
                 Self::update_commitment_cache(block_commitment_cache, aggregation_data, ancestors);
@@ -127,6 +186,32 @@ impl AggregateCommitmentService {
                 ),
             );
 
+            if let Some(epoch_super_majority_root) = epoch_super_majority_root_tracker.update(
+                bank.epoch_schedule(),
+                bank.epoch(),
+                update_commitment_slots.root,
+                update_commitment_slots.highest_super_majority_root,
+            ) {
+                // Measured from the cluster's timestamp of the first slot in the epoch
+                let epoch_start_ms =
+                    (bank.clock().epoch_start_timestamp.max(0) as u64).saturating_mul(1000);
+                datapoint_info!(
+                    "block-commitment-cache-epoch-super-majority-root",
+                    ("epoch", epoch_super_majority_root.epoch, i64),
+                    (
+                        "elapsed-ms",
+                        timestamp().saturating_sub(epoch_start_ms),
+                        i64
+                    ),
+                    (
+                        "highest-super-majority-root",
+                        epoch_super_majority_root.highest_super_majority_root,
+                        i64
+                    ),
+                    ("root-lag", epoch_super_majority_root.root_lag, i64),
+                );
+            }
+
             // Triggers rpc_subscription notifications as soon as new commitment data is available,
             // sending just the commitment cache slot information that the notifications thread
             // needs
@@ -286,6 +371,30 @@ mod tests {
         assert_eq!(get_highest_super_majority_root(rooted_stake, 10), 1);
     }
 
+    #[test]
+    fn test_epoch_super_majority_root_tracker() {
+        let epoch_schedule = EpochSchedule::custom(32, 32, false);
+        let mut tracker = EpochSuperMajorityRootTracker::default();
+
+        // The epoch the tracker starts in is not reported
+        assert_eq!(tracker.update(&epoch_schedule, 0, 10, 12), None);
+        assert_eq!(tracker.update(&epoch_schedule, 0, 20, 22), None);
+
+        assert_eq!(tracker.update(&epoch_schedule, 1, 25, 28), None);
+        assert_eq!(tracker.update(&epoch_schedule, 1, 30, 31), None);
+
+        let epoch_super_majority_root = tracker.update(&epoch_schedule, 1, 30, 33).unwrap();
+        assert_eq!(epoch_super_majority_root.epoch, 1);
+        assert_eq!(epoch_super_majority_root.highest_super_majority_root, 33);
+        assert_eq!(epoch_super_majority_root.root_lag, 3);
+
+        // Only the first supermajority root of the epoch is reported
+        assert_eq!(tracker.update(&epoch_schedule, 1, 34, 40), None);
+
+        // Nor is an epoch our bank has already moved past
+        assert_eq!(tracker.update(&epoch_schedule, 3, 60, 70), None);
+    }
+
     #[test]
     fn test_aggregate_commitment_for_vote_account_1() {
         let ancestors = vec![3, 4, 5, 7, 9, 11];
@@ -674,6 +674,21 @@ impl HeaviestSubtreeForkChoice {
             .map(|fork_info| fork_info.stake_voted_at)
     }
 
+    /// Returns the stake voted on forks that do not contain the node with key `slot_hash_key`,
+    /// excluding votes on its ancestors, which support both.
+    pub fn stake_voted_on_competing_forks(&self, slot_hash_key: &SlotHashKey) -> Option<u64> {
+        let stake_voted_subtree = self.stake_voted_subtree(slot_hash_key)?;
+        let stake_voted_ancestors: u64 = self
+            .ancestor_iterator(*slot_hash_key)
+            .filter_map(|ancestor_key| self.stake_voted_at(&ancestor_key))
+            .sum();
+        Some(
+            self.stake_voted_subtree(&self.tree_root)?
+                .saturating_sub(stake_voted_subtree)
+                .saturating_sub(stake_voted_ancestors),
+        )
+    }
+
     pub fn latest_invalid_ancestor(&self, slot_hash_key: &SlotHashKey) -> Option<Slot> {
         self.fork_infos
             .get(slot_hash_key)
@@ -2504,6 +2519,43 @@ mod test {
         assert_eq!(heaviest_subtree_fork_choice.best_overall_slot().0, 4)
     }
 
+    #[test]
+    fn test_stake_voted_on_competing_forks() {
+        let mut heaviest_subtree_fork_choice = setup_forks();
+        let stake = 100;
+        let (bank, vote_pubkeys) = bank_utils::setup_bank_and_vote_pubkeys_for_tests(4, stake);
+
+        let pubkey_votes: Vec<(Pubkey, SlotHashKey)> = vec![
+            (vote_pubkeys[0], (5, Hash::default())),
+            (vote_pubkeys[1], (6, Hash::default())),
+            (vote_pubkeys[2], (4, Hash::default())),
+            (vote_pubkeys[3], (1, Hash::default())),
+        ];
+        heaviest_subtree_fork_choice.add_votes(
+            pubkey_votes.iter(),
+            bank.epoch_stakes_map(),
+            bank.epoch_schedule(),
+        );
+
+        // The vote on slot 1 supports both forks
+        assert_eq!(
+            heaviest_subtree_fork_choice.stake_voted_on_competing_forks(&(5, Hash::default())),
+            Some(stake)
+        );
+        assert_eq!(
+            heaviest_subtree_fork_choice.stake_voted_on_competing_forks(&(2, Hash::default())),
+            Some(2 * stake)
+        );
+        assert_eq!(
+            heaviest_subtree_fork_choice.stake_voted_on_competing_forks(&(1, Hash::default())),
+            Some(0)
+        );
+        assert_eq!(
+            heaviest_subtree_fork_choice.stake_voted_on_competing_forks(&(7, Hash::default())),
+            None
+        );
+    }
+
     #[test]
     fn test_add_votes_duplicate_tie() {
         let (
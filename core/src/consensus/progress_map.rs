@@ -84,6 +84,10 @@ impl ReplaySlotStats {
         };
 
         self.batch_execute.slowest_thread.report_stats(slot);
+        self.batch_execute
+            .totals
+            .transaction_compute_units
+            .report("replay-slot-transaction-compute-units", slot);
 
         let mut per_pubkey_timings: Vec<_> = self
             .batch_execute
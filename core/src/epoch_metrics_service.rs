@@ -0,0 +1,1343 @@
+//! The `epoch_metrics_service` observes new roots and reports cluster and validator metrics
+//! about the current epoch as it progresses, and about each epoch once the first root of the
+//! following epoch is made.
+
+use {
+    crossbeam_channel::{Receiver, RecvTimeoutError},
+    solana_gossip::{cluster_info::ClusterInfo, crds::Cursor, crds_value::SnapshotHashes},
+    solana_ledger::leader_schedule_cache::LeaderScheduleCache,
+    solana_runtime::{bank::Bank, bank_forks::BankForks},
+    solana_sdk::{
+        account::{from_account, AccountSharedData, ReadableAccount},
+        account_utils::StateMut,
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        clock::{Epoch, Slot},
+        feature, feature_set,
+        hash::Hash,
+        pubkey::Pubkey,
+        slot_history::{Check, SlotHistory},
+        stake_history::StakeHistory,
+        sysvar,
+        timing::AtomicInterval,
+        vote::state::{VoteState, VOTE_CREDITS_MAXIMUM_PER_SLOT},
+    },
+    solana_vote::{vote_account::VoteAccountsHashMap, vote_parser},
+    std::{
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+        mem,
+        sync::{Arc, RwLock},
+        thread::{self, Builder, JoinHandle},
+        time::Duration,
+    },
+};
+
+/// Number of most recent `StakeHistory` entries reported at each new epoch
+const STAKE_HISTORY_REPORTED_EPOCHS: usize = 5;
+
+/// Commission changes made within this many slots of the end of an epoch take effect for its
+/// rewards before delegators can react
+const COMMISSION_FINAL_SLOTS: u64 = 1_000;
+
+/// Pending feature activations are reported at most this often, in slots, so that they show up
+/// well before the epoch boundary that activates them
+const PENDING_FEATURES_REPORT_INTERVAL_SLOTS: u64 = 1_000;
+
+/// Watched accounts are reported at most this often, in slots, unless an owner or data size
+/// changes
+const WATCHED_ACCOUNTS_REPORT_INTERVAL_SLOTS: u64 = 150;
+
+/// Metrics observed from gossip are reported on this interval, whether or not new roots are made
+const GOSSIP_METRICS_REPORT_INTERVAL_MS: u64 = 10_000;
+
+pub struct EpochMetricsService {
+    thread_hdl: JoinHandle<()>,
+}
+
+impl EpochMetricsService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        bank_forks: Arc<RwLock<BankForks>>,
+        cluster_info: Arc<ClusterInfo>,
+        leader_schedule_cache: Arc<LeaderScheduleCache>,
+        vote_account: Pubkey,
+        known_validators: Option<HashSet<Pubkey>>,
+        watched_accounts: HashSet<Pubkey>,
+        watched_programs: HashSet<Pubkey>,
+        root_receiver: Receiver<Slot>,
+    ) -> Self {
+        let thread_hdl = Builder::new()
+            .name("solEpochMetrics".to_string())
+            .spawn(move || {
+                Self::service_loop(
+                    bank_forks,
+                    cluster_info,
+                    leader_schedule_cache,
+                    vote_account,
+                    known_validators,
+                    watched_accounts,
+                    watched_programs,
+                    root_receiver,
+                );
+            })
+            .unwrap();
+
+        Self { thread_hdl }
+    }
+
+    pub fn join(self) -> thread::Result<()> {
+        self.thread_hdl.join()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn service_loop(
+        bank_forks: Arc<RwLock<BankForks>>,
+        cluster_info: Arc<ClusterInfo>,
+        leader_schedule_cache: Arc<LeaderScheduleCache>,
+        vote_account: Pubkey,
+        known_validators: Option<HashSet<Pubkey>>,
+        watched_accounts: HashSet<Pubkey>,
+        watched_programs: HashSet<Pubkey>,
+        root_receiver: Receiver<Slot>,
+    ) {
+        let mut last_root_bank: Option<Arc<Bank>> = None;
+        let mut last_pending_features_report_slot: Option<Slot> = None;
+        let mut skip_rate = SkipRate::default();
+        let mut vote_credit_loss = VoteCreditLoss::default();
+        let mut commission_changes = CommissionChanges::default();
+        let mut vote_inclusion_by_leader = VoteInclusionByLeader::default();
+        let mut known_validators_tip = known_validators.clone().map(KnownValidatorsTip::new);
+        let mut watched_accounts = WatchedAccounts::new(watched_accounts);
+        let mut watched_programs = WatchedPrograms::new(watched_programs);
+        let gossip_metrics_interval = AtomicInterval::default();
+        loop {
+            let recv_timeout = Duration::from_millis(GOSSIP_METRICS_REPORT_INTERVAL_MS);
+            let root = match root_receiver.recv_timeout(recv_timeout) {
+                // Only the most recent root is of interest if several queued up
+                Ok(root) => Some(root_receiver.try_iter().last().unwrap_or(root)),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+
+            if let Some(root_bank) = root.and_then(|root| bank_forks.read().unwrap().get(root)) {
+                let root = root_bank.slot();
+                let epoch = root_bank.epoch();
+                if let Some(last_root_bank) = last_root_bank.as_ref() {
+                    if epoch > last_root_bank.epoch() {
+                        Self::report_completed_epoch(&root_bank, last_root_bank, &vote_account);
+                    }
+                }
+                if last_root_bank.as_ref().map(|bank| bank.epoch()) != Some(epoch) {
+                    Self::report_stake_history(&root_bank);
+                }
+                last_root_bank = Some(root_bank.clone());
+                if last_pending_features_report_slot.map_or(true, |last_slot| {
+                    root.saturating_sub(last_slot) >= PENDING_FEATURES_REPORT_INTERVAL_SLOTS
+                }) {
+                    Self::report_pending_features(&root_bank);
+                    last_pending_features_report_slot = Some(root);
+                }
+
+                // Loaded once for all the trackers below, the slot history is a large sysvar
+                let slot_history = root_bank.get_slot_history();
+                let vote_accounts = root_bank.vote_accounts();
+                let vote_state = vote_accounts
+                    .get(&vote_account)
+                    .and_then(|(_, vote_account)| vote_account.vote_state().ok());
+
+                skip_rate.update(
+                    &root_bank,
+                    &slot_history,
+                    &leader_schedule_cache,
+                    &cluster_info.id(),
+                );
+                skip_rate.report();
+
+                if let Some(vote_state) = vote_state {
+                    vote_credit_loss.update(&root_bank, &slot_history, vote_state);
+                }
+                vote_credit_loss.report();
+
+                commission_changes.update(&root_bank, &vote_account);
+
+                vote_inclusion_by_leader.update(
+                    &root_bank,
+                    &slot_history,
+                    &leader_schedule_cache,
+                    vote_state,
+                );
+                vote_inclusion_by_leader.report();
+
+                watched_accounts.update(&root_bank);
+                watched_accounts.report(root);
+
+                watched_programs.update(&root_bank);
+                watched_programs.report(root);
+            }
+
+            if gossip_metrics_interval.should_update(GOSSIP_METRICS_REPORT_INTERVAL_MS) {
+                if let Some(known_validators_tip) = known_validators_tip.as_mut() {
+                    known_validators_tip.update(&cluster_info);
+                    known_validators_tip.report(bank_forks.read().unwrap().highest_slot());
+                }
+                if let Some(known_validators) = known_validators.as_ref() {
+                    Self::report_snapshot_hash_agreement(&cluster_info, known_validators);
+                }
+            }
+        }
+    }
+
+    /// `root_bank` is the first root observed past the end of the completed epoch, and
+    /// `last_epoch_root_bank` the last root observed within it
+    fn report_completed_epoch(
+        root_bank: &Bank,
+        last_epoch_root_bank: &Bank,
+        vote_account: &Pubkey,
+    ) {
+        Self::report_vote_credits(root_bank, last_epoch_root_bank, vote_account);
+    }
+
+    /// The stake history only changes at epoch boundaries, so it is reported once per epoch
+    fn report_stake_history(root_bank: &Bank) {
+        let Some(stake_history) = root_bank
+            .get_account(&sysvar::stake_history::id())
+            .and_then(|account| from_account::<StakeHistory, _>(&account))
+        else {
+            return;
+        };
+        for (epoch, entry) in stake_history.iter().take(STAKE_HISTORY_REPORTED_EPOCHS) {
+            datapoint_info!(
+                "epoch_metrics-stake_history",
+                ("epoch", *epoch, i64),
+                ("effective", entry.effective, i64),
+                ("activating", entry.activating, i64),
+                ("deactivating", entry.deactivating, i64),
+            );
+        }
+    }
+
+    /// Feature gates whose feature account exists but has not been activated yet, which happens at
+    /// the next epoch boundary
+    fn report_pending_features(root_bank: &Bank) {
+        let pending_features: Vec<_> = root_bank
+            .feature_set
+            .inactive
+            .iter()
+            .filter(|feature_id| {
+                root_bank
+                    .get_account(feature_id)
+                    .and_then(|account| feature::from_account(&account))
+                    .map_or(false, |feature| feature.activated_at.is_none())
+            })
+            .collect();
+        let activation_epoch = root_bank.epoch().saturating_add(1);
+        datapoint_info!(
+            "epoch_metrics-pending_features",
+            ("activation_epoch", activation_epoch, i64),
+            ("pending_features", pending_features.len(), i64),
+        );
+        for feature_id in pending_features {
+            datapoint_info!(
+                "epoch_metrics-pending_feature",
+                ("activation_epoch", activation_epoch, i64),
+                ("feature_id", feature_id.to_string(), String),
+                (
+                    "description",
+                    feature_set::FEATURE_NAMES
+                        .get(feature_id)
+                        .copied()
+                        .unwrap_or_default(),
+                    String
+                ),
+            );
+        }
+    }
+
+    /// Compares the latest snapshot hash we advertise in gossip, incremental if there is one, with
+    /// the snapshot hashes the known validators advertise for the same slot
+    fn report_snapshot_hash_agreement(
+        cluster_info: &ClusterInfo,
+        known_validators: &HashSet<Pubkey>,
+    ) {
+        let Some(snapshot_hashes) = cluster_info.get_snapshot_hashes_for_node(&cluster_info.id())
+        else {
+            return;
+        };
+        let snapshot_hash = latest_snapshot_hash(&snapshot_hashes);
+        let known_validators_snapshot_hashes: Vec<_> = known_validators
+            .iter()
+            .filter_map(|pubkey| cluster_info.get_snapshot_hashes_for_node(pubkey))
+            .collect();
+        let (num_at_slot, num_matching) =
+            snapshot_hash_agreement(snapshot_hash, &known_validators_snapshot_hashes);
+
+        datapoint_info!(
+            "epoch_metrics-snapshot_hash_agreement",
+            ("slot", snapshot_hash.0, i64),
+            (
+                "is_incremental",
+                !snapshot_hashes.incremental.is_empty(),
+                bool
+            ),
+            ("known_validators", known_validators.len(), i64),
+            ("known_validators_at_slot", num_at_slot, i64),
+            ("known_validators_matching", num_matching, i64),
+            (
+                "known_validators_mismatching",
+                num_at_slot.saturating_sub(num_matching),
+                i64
+            ),
+        );
+    }
+
+    /// Credits are only earned for votes on rooted blocks, so the maximum counts the rooted blocks
+    /// of the epoch rather than all of its slots. The credits per block are those of the completed
+    /// epoch, as a feature activated at the boundary only applies to the following one.
+    fn report_vote_credits(root_bank: &Bank, last_epoch_root_bank: &Bank, vote_account: &Pubkey) {
+        let epoch = last_epoch_root_bank.epoch();
+        let Some(epoch_vote_accounts) = root_bank.epoch_vote_accounts(epoch) else {
+            return;
+        };
+        let epoch_schedule = root_bank.epoch_schedule();
+        let slot_history = root_bank.get_slot_history();
+        let rooted_blocks = (epoch_schedule.get_first_slot_in_epoch(epoch)
+            ..=epoch_schedule.get_last_slot_in_epoch(epoch))
+            .filter(|slot| slot_history.check(*slot) == Check::Found)
+            .count() as u64;
+        let max_credits = rooted_blocks.saturating_mul(max_credits_per_slot(last_epoch_root_bank));
+
+        let vote_accounts = root_bank.vote_accounts();
+        let credits_earned = |pubkey: &Pubkey| {
+            vote_accounts
+                .get(pubkey)
+                .and_then(|(_, vote_account)| vote_account.vote_state().ok())
+                .map(|vote_state| credits_earned_in_epoch(vote_state.epoch_credits(), epoch))
+                .unwrap_or_default()
+        };
+
+        let (total_stake, stake_weighted_credits) = epoch_vote_accounts.iter().fold(
+            (0u128, 0u128),
+            |(total_stake, stake_weighted_credits), (pubkey, (stake, _))| {
+                (
+                    total_stake.saturating_add(u128::from(*stake)),
+                    stake_weighted_credits
+                        .saturating_add(u128::from(*stake) * u128::from(credits_earned(pubkey))),
+                )
+            },
+        );
+        if total_stake == 0 || max_credits == 0 {
+            return;
+        }
+        let cluster_efficiency =
+            stake_weighted_credits as f64 / total_stake as f64 / max_credits as f64;
+        let credits = credits_earned(vote_account);
+
+        datapoint_info!(
+            "epoch_metrics-vote_credits",
+            ("epoch", epoch, i64),
+            ("max_credits", max_credits, i64),
+            ("credits", credits, i64),
+            ("efficiency", credits as f64 / max_credits as f64, f64),
+            ("cluster_stake_weighted_efficiency", cluster_efficiency, f64),
+        );
+    }
+}
+
+/// Commissions of all staked vote accounts at the first root of the epoch, at the first root
+/// within its final slots and at its last root. The commission as of the last slot of an epoch
+/// is the one its rewards are paid out with.
+#[derive(Default)]
+struct CommissionChanges {
+    epoch: Option<Epoch>,
+    /// Whether the first root of `epoch` was observed, rather than a root later in the epoch
+    /// when the service started
+    observed_start: bool,
+    start_commissions: HashMap<Pubkey, u8>,
+    final_slots_commissions: Option<HashMap<Pubkey, u8>>,
+    last_root_vote_accounts: Option<Arc<VoteAccountsHashMap>>,
+}
+
+impl CommissionChanges {
+    fn update(&mut self, root_bank: &Bank, vote_account: &Pubkey) {
+        let epoch = root_bank.epoch();
+        if self.epoch != Some(epoch) {
+            if let Some(last_root_vote_accounts) = self.last_root_vote_accounts.take() {
+                if self.observed_start {
+                    self.report(&commissions(&last_root_vote_accounts), vote_account);
+                }
+            }
+            self.observed_start = self.epoch.is_some();
+            self.epoch = Some(epoch);
+            self.start_commissions = commissions(&root_bank.vote_accounts());
+            self.final_slots_commissions = None;
+        }
+
+        let (_, slot_index) = root_bank.get_epoch_and_slot_index(root_bank.slot());
+        let final_slots_start = root_bank
+            .get_slots_in_epoch(epoch)
+            .saturating_sub(COMMISSION_FINAL_SLOTS);
+        if self.final_slots_commissions.is_none() && slot_index >= final_slots_start {
+            self.final_slots_commissions = Some(commissions(&root_bank.vote_accounts()));
+        }
+        self.last_root_vote_accounts = Some(root_bank.vote_accounts());
+    }
+
+    fn report(&self, end_commissions: &HashMap<Pubkey, u8>, vote_account: &Pubkey) {
+        let Some(epoch) = self.epoch else {
+            return;
+        };
+        let changed = changed_commissions(&self.start_commissions, end_commissions);
+        let changed_in_final_slots = self
+            .final_slots_commissions
+            .as_ref()
+            .map(|final_slots_commissions| {
+                changed_commissions(final_slots_commissions, end_commissions)
+            })
+            .unwrap_or_default();
+
+        for (pubkey, commission, end_commission) in &changed_in_final_slots {
+            datapoint_info!(
+                "epoch_metrics-commission_change_in_final_slots",
+                ("epoch", epoch, i64),
+                ("vote_account", pubkey.to_string(), String),
+                ("commission", *commission, i64),
+                ("end_commission", *end_commission, i64),
+            );
+        }
+        let is_own = |(pubkey, ..): &(Pubkey, u8, u8)| pubkey == vote_account;
+        datapoint_info!(
+            "epoch_metrics-commission_changes",
+            ("epoch", epoch, i64),
+            ("vote_accounts", end_commissions.len(), i64),
+            ("changed", changed.len(), i64),
+            ("changed_in_final_slots", changed_in_final_slots.len(), i64),
+            (
+                "final_slots_observed",
+                self.final_slots_commissions.is_some(),
+                bool
+            ),
+            (
+                "own_commission",
+                end_commissions.get(vote_account).copied(),
+                Option<i64>
+            ),
+            ("own_changed", changed.iter().any(is_own), bool),
+            (
+                "own_changed_in_final_slots",
+                changed_in_final_slots.iter().any(is_own),
+                bool
+            ),
+        );
+    }
+}
+
+fn commissions(vote_accounts: &VoteAccountsHashMap) -> HashMap<Pubkey, u8> {
+    vote_accounts
+        .iter()
+        .filter(|(_, (stake, _))| *stake > 0)
+        .filter_map(|(pubkey, (_, vote_account))| {
+            let vote_state = vote_account.vote_state().ok()?;
+            Some((*pubkey, vote_state.commission))
+        })
+        .collect()
+}
+
+/// `(vote account, commission before, commission after)` of the vote accounts present in both
+/// whose commission differs
+fn changed_commissions(
+    before: &HashMap<Pubkey, u8>,
+    after: &HashMap<Pubkey, u8>,
+) -> Vec<(Pubkey, u8, u8)> {
+    after
+        .iter()
+        .filter_map(|(pubkey, after)| {
+            let before = before.get(pubkey)?;
+            (before != after).then_some((*pubkey, *before, *after))
+        })
+        .collect()
+}
+
+/// Leader slots of the current epoch up to the latest root, and how many of them were skipped,
+/// for the whole cluster and for this validator's identity.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SkipRate {
+    epoch: Epoch,
+    /// First slot not yet accounted for
+    next_slot: Slot,
+    leader_slots: u64,
+    skipped_slots: u64,
+    own_leader_slots: u64,
+    own_skipped_slots: u64,
+}
+
+impl SkipRate {
+    fn update(
+        &mut self,
+        root_bank: &Bank,
+        slot_history: &SlotHistory,
+        leader_schedule_cache: &LeaderScheduleCache,
+        identity: &Pubkey,
+    ) {
+        let epoch = root_bank.epoch();
+        if epoch != self.epoch || self.next_slot == 0 {
+            *self = Self {
+                epoch,
+                next_slot: root_bank.epoch_schedule().get_first_slot_in_epoch(epoch),
+                ..Self::default()
+            };
+        }
+
+        for slot in self.next_slot..=root_bank.slot() {
+            let is_own_leader_slot =
+                leader_schedule_cache.slot_leader_at(slot, Some(root_bank)) == Some(*identity);
+            self.record_slot(is_own_leader_slot, slot_history.check(slot) != Check::Found);
+        }
+        self.next_slot = root_bank.slot().saturating_add(1);
+    }
+
+    fn record_slot(&mut self, is_own_leader_slot: bool, skipped: bool) {
+        self.leader_slots = self.leader_slots.saturating_add(1);
+        self.skipped_slots = self.skipped_slots.saturating_add(u64::from(skipped));
+        if is_own_leader_slot {
+            self.own_leader_slots = self.own_leader_slots.saturating_add(1);
+            self.own_skipped_slots = self.own_skipped_slots.saturating_add(u64::from(skipped));
+        }
+    }
+
+    fn cluster_skip_rate(&self) -> Option<f64> {
+        (self.leader_slots > 0).then(|| self.skipped_slots as f64 / self.leader_slots as f64)
+    }
+
+    fn own_skip_rate(&self) -> Option<f64> {
+        (self.own_leader_slots > 0)
+            .then(|| self.own_skipped_slots as f64 / self.own_leader_slots as f64)
+    }
+
+    fn report(&self) {
+        datapoint_info!(
+            "epoch_metrics-skip_rate",
+            ("epoch", self.epoch, i64),
+            ("leader_slots", self.leader_slots, i64),
+            ("skipped_slots", self.skipped_slots, i64),
+            ("cluster_skip_rate", self.cluster_skip_rate(), Option<f64>),
+            ("own_leader_slots", self.own_leader_slots, i64),
+            ("own_skipped_slots", self.own_skipped_slots, i64),
+            ("own_skip_rate", self.own_skip_rate(), Option<f64>),
+        );
+    }
+}
+
+/// Credits this validator lost on the rooted blocks of the current epoch, attributed to why fewer
+/// than the maximum credits were earned for each block.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct VoteCreditLoss {
+    epoch: Epoch,
+    /// First slot not yet attributed
+    next_slot: Slot,
+    /// Credits each vote in our tower earns once rooted, by voted slot
+    landed_vote_credits: BTreeMap<Slot, u64>,
+    /// Voted slots that were popped from our tower by lockout expiry instead of being rooted
+    expired_votes: BTreeSet<Slot>,
+    max_credits_per_slot: u64,
+    rooted_blocks: u64,
+    /// Credits lost to votes landing after the grace period
+    late_vote_loss: u64,
+    /// Credits lost to blocks we never landed a vote for
+    missed_vote_loss: u64,
+    /// Credits lost to votes that expired from our tower before being rooted
+    lockout_loss: u64,
+}
+
+impl VoteCreditLoss {
+    fn update(&mut self, root_bank: &Bank, slot_history: &SlotHistory, vote_state: &VoteState) {
+        let Some(vote_root) = vote_state.root_slot else {
+            return;
+        };
+
+        let epoch = root_bank.epoch();
+        if epoch != self.epoch && self.next_slot != 0 {
+            // Finish the completed epoch with the credits per block it was set up with, which were
+            // taken from one of its own banks
+            let last_slot = root_bank
+                .epoch_schedule()
+                .get_last_slot_in_epoch(self.epoch);
+            self.observe_tower(tower_vote_credits(vote_state, root_bank), vote_root);
+            self.attribute_rooted_blocks(slot_history, vote_root.min(last_slot));
+            self.report();
+        }
+        if epoch != self.epoch || self.next_slot == 0 {
+            let first_slot = root_bank.epoch_schedule().get_first_slot_in_epoch(epoch);
+            // Votes rooted before the service started are unknown, so start past them
+            let next_slot = if self.next_slot == 0 {
+                first_slot.max(vote_root.saturating_add(1))
+            } else {
+                first_slot
+            };
+            *self = Self {
+                epoch,
+                next_slot,
+                landed_vote_credits: mem::take(&mut self.landed_vote_credits),
+                expired_votes: mem::take(&mut self.expired_votes),
+                ..Self::default()
+            };
+        }
+        self.max_credits_per_slot = max_credits_per_slot(root_bank);
+
+        self.observe_tower(tower_vote_credits(vote_state, root_bank), vote_root);
+
+        self.attribute_rooted_blocks(slot_history, vote_root.min(root_bank.slot()));
+    }
+
+    /// Attributes the rooted blocks from `next_slot` through `last_slot`
+    fn attribute_rooted_blocks(&mut self, slot_history: &SlotHistory, last_slot: Slot) {
+        for slot in self.next_slot..=last_slot {
+            if slot_history.check(slot) == Check::Found {
+                self.attribute_rooted_block(slot);
+            }
+        }
+        self.next_slot = self.next_slot.max(last_slot.saturating_add(1));
+        // Votes on slots that were not rooted will never earn credits
+        self.landed_vote_credits = self.landed_vote_credits.split_off(&self.next_slot);
+        self.expired_votes = self.expired_votes.split_off(&self.next_slot);
+    }
+
+    /// `tower` holds the voted slots of our tower and the credits each vote earns once rooted
+    fn observe_tower(&mut self, tower: Vec<(Slot, u64)>, vote_root: Slot) {
+        let tower_slots: BTreeSet<_> = tower.iter().map(|(slot, _)| *slot).collect();
+        let mut expired_votes = vec![];
+        self.landed_vote_credits.retain(|slot, _| {
+            let expired = *slot > vote_root && !tower_slots.contains(slot);
+            if expired {
+                expired_votes.push(*slot);
+            }
+            !expired
+        });
+        self.expired_votes.extend(expired_votes);
+        for (slot, credits) in tower {
+            self.landed_vote_credits.entry(slot).or_insert(credits);
+        }
+    }
+
+    fn attribute_rooted_block(&mut self, slot: Slot) {
+        self.rooted_blocks = self.rooted_blocks.saturating_add(1);
+        if let Some(credits) = self.landed_vote_credits.remove(&slot) {
+            self.late_vote_loss = self
+                .late_vote_loss
+                .saturating_add(self.max_credits_per_slot.saturating_sub(credits));
+        } else if self.expired_votes.remove(&slot) {
+            self.lockout_loss = self.lockout_loss.saturating_add(self.max_credits_per_slot);
+        } else {
+            self.missed_vote_loss = self
+                .missed_vote_loss
+                .saturating_add(self.max_credits_per_slot);
+        }
+    }
+
+    fn report(&self) {
+        if self.rooted_blocks == 0 {
+            return;
+        }
+        datapoint_info!(
+            "epoch_metrics-vote_credit_loss",
+            ("epoch", self.epoch, i64),
+            ("rooted_blocks", self.rooted_blocks, i64),
+            (
+                "max_credits",
+                self.rooted_blocks.saturating_mul(self.max_credits_per_slot),
+                i64
+            ),
+            ("late_vote_loss", self.late_vote_loss, i64),
+            ("missed_vote_loss", self.missed_vote_loss, i64),
+            ("lockout_loss", self.lockout_loss, i64),
+        );
+    }
+}
+
+fn max_credits_per_slot(bank: &Bank) -> u64 {
+    if bank
+        .feature_set
+        .is_active(&feature_set::timely_vote_credits::id())
+    {
+        u64::from(VOTE_CREDITS_MAXIMUM_PER_SLOT)
+    } else {
+        1
+    }
+}
+
+/// The voted slots of `vote_state`'s tower and the credits each vote earns once rooted
+fn tower_vote_credits(vote_state: &VoteState, bank: &Bank) -> Vec<(Slot, u64)> {
+    let timely_vote_credits = bank
+        .feature_set
+        .is_active(&feature_set::timely_vote_credits::id());
+    vote_state
+        .votes
+        .iter()
+        .enumerate()
+        .map(|(index, vote)| {
+            let credits = if timely_vote_credits {
+                vote_state.credits_for_vote_at_index(index)
+            } else {
+                1
+            };
+            (vote.slot(), credits)
+        })
+        .collect()
+}
+
+/// Blocks rooted in the current epoch and how many of our vote transactions landed in them, by the
+/// leader that produced them, to tell leaders that persistently drop our votes.
+#[derive(Debug, Default)]
+struct VoteInclusionByLeader {
+    epoch: Epoch,
+    /// First slot whose block is not yet counted
+    next_slot: Slot,
+    /// Highest slot a vote of ours was counted as landing in
+    last_landing_slot: Slot,
+    leaders: HashMap<Pubkey, LeaderVoteInclusion>,
+    /// Leaders whose counts changed since they were last reported
+    updated_leaders: HashSet<Pubkey>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct LeaderVoteInclusion {
+    blocks: u64,
+    landed_votes: u64,
+}
+
+impl VoteInclusionByLeader {
+    fn update(
+        &mut self,
+        root_bank: &Bank,
+        slot_history: &SlotHistory,
+        leader_schedule_cache: &LeaderScheduleCache,
+        vote_state: Option<&VoteState>,
+    ) {
+        let epoch = root_bank.epoch();
+        if epoch != self.epoch || self.next_slot == 0 {
+            // Votes landed before the service started are unknown, so start past the root
+            let (next_slot, last_landing_slot) = if self.next_slot == 0 {
+                (root_bank.slot().saturating_add(1), root_bank.slot())
+            } else {
+                (
+                    root_bank.epoch_schedule().get_first_slot_in_epoch(epoch),
+                    self.last_landing_slot,
+                )
+            };
+            *self = Self {
+                epoch,
+                next_slot,
+                last_landing_slot,
+                ..Self::default()
+            };
+        }
+
+        for slot in self.next_slot..=root_bank.slot() {
+            if slot_history.check(slot) != Check::Found {
+                continue;
+            }
+            if let Some(leader) = leader_schedule_cache.slot_leader_at(slot, Some(root_bank)) {
+                self.record_block(leader);
+            }
+        }
+        self.next_slot = self.next_slot.max(root_bank.slot().saturating_add(1));
+
+        let Some(vote_state) = vote_state else {
+            return;
+        };
+        for landing_slot in
+            landing_slots(vote_state).range(self.last_landing_slot.saturating_add(1)..)
+        {
+            if let Some(leader) =
+                leader_schedule_cache.slot_leader_at(*landing_slot, Some(root_bank))
+            {
+                self.record_landed_vote(leader);
+            }
+            self.last_landing_slot = *landing_slot;
+        }
+    }
+
+    fn record_block(&mut self, leader: Pubkey) {
+        let leader_vote_inclusion = self.leaders.entry(leader).or_default();
+        leader_vote_inclusion.blocks = leader_vote_inclusion.blocks.saturating_add(1);
+        self.updated_leaders.insert(leader);
+    }
+
+    fn record_landed_vote(&mut self, leader: Pubkey) {
+        let leader_vote_inclusion = self.leaders.entry(leader).or_default();
+        leader_vote_inclusion.landed_votes = leader_vote_inclusion.landed_votes.saturating_add(1);
+        self.updated_leaders.insert(leader);
+    }
+
+    fn report(&mut self) {
+        for leader in self.updated_leaders.drain() {
+            let Some(leader_vote_inclusion) = self.leaders.get(&leader) else {
+                continue;
+            };
+            datapoint_info!(
+                "epoch_metrics-vote_inclusion_by_leader",
+                ("epoch", self.epoch, i64),
+                ("leader", leader.to_string(), String),
+                ("blocks", leader_vote_inclusion.blocks, i64),
+                ("landed_votes", leader_vote_inclusion.landed_votes, i64),
+            );
+        }
+    }
+}
+
+/// The slots the votes in `vote_state`'s tower landed in, as recorded by their latency. All the
+/// slots a vote transaction adds to the tower share its landing slot.
+fn landing_slots(vote_state: &VoteState) -> BTreeSet<Slot> {
+    vote_state
+        .votes
+        .iter()
+        // Latency is 0 when it was not recorded and saturates for very late votes
+        .filter(|vote| vote.latency > 0 && vote.latency < u8::MAX)
+        .map(|vote| vote.slot().saturating_add(u64::from(vote.latency)))
+        .collect()
+}
+
+/// The highest slots the known validators have voted on, as seen in their gossip votes.
+struct KnownValidatorsTip {
+    known_validators: HashSet<Pubkey>,
+    cursor: Cursor,
+    voted_slots: HashMap<Pubkey, Slot>,
+}
+
+impl KnownValidatorsTip {
+    fn new(known_validators: HashSet<Pubkey>) -> Self {
+        Self {
+            known_validators,
+            cursor: Cursor::default(),
+            voted_slots: HashMap::default(),
+        }
+    }
+
+    fn update(&mut self, cluster_info: &ClusterInfo) {
+        let (labels, votes) = cluster_info.get_votes_with_labels(&mut self.cursor);
+        for (label, vote) in labels.iter().zip(votes.iter()) {
+            let pubkey = label.pubkey();
+            if !self.known_validators.contains(&pubkey) {
+                continue;
+            }
+            let Some(slot) = vote_parser::parse_vote_transaction(vote)
+                .and_then(|(_, vote, ..)| vote.last_voted_slot())
+            else {
+                continue;
+            };
+            self.record_vote(pubkey, slot);
+        }
+    }
+
+    fn record_vote(&mut self, pubkey: Pubkey, slot: Slot) {
+        let voted_slot = self.voted_slots.entry(pubkey).or_default();
+        *voted_slot = (*voted_slot).max(slot);
+    }
+
+    fn max_voted_slot(&self) -> Option<Slot> {
+        self.voted_slots.values().max().copied()
+    }
+
+    fn report(&self, highest_slot: Slot) {
+        let Some(max_voted_slot) = self.max_voted_slot() else {
+            return;
+        };
+        datapoint_info!(
+            "epoch_metrics-known_validators_tip",
+            ("known_validators", self.known_validators.len(), i64),
+            ("known_validators_voting", self.voted_slots.len(), i64),
+            ("max_voted_slot", max_voted_slot, i64),
+            ("highest_slot", highest_slot, i64),
+            (
+                "slots_behind",
+                max_voted_slot.saturating_sub(highest_slot),
+                i64
+            ),
+        );
+    }
+}
+
+/// Lamports, owner and data size of the configured accounts as of the latest root, and how many
+/// times the owner or data size changed since the validator started.
+struct WatchedAccounts {
+    accounts: HashMap<Pubkey, WatchedAccount>,
+    last_report_slot: Option<Slot>,
+    /// Whether an owner or data size changed since the last report
+    changed: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct WatchedAccount {
+    lamports: u64,
+    /// Lamports above the rent exempt reserve, which for a vote account are the commission
+    /// rewards that can be withdrawn without closing it
+    withdrawable: u64,
+    /// `None` until the account is first observed
+    owner_and_data_len: Option<(Pubkey, usize)>,
+    changes: u64,
+}
+
+impl WatchedAccounts {
+    fn new(pubkeys: HashSet<Pubkey>) -> Self {
+        Self {
+            accounts: pubkeys
+                .into_iter()
+                .map(|pubkey| (pubkey, WatchedAccount::default()))
+                .collect(),
+            last_report_slot: None,
+            changed: false,
+        }
+    }
+
+    fn update(&mut self, root_bank: &Bank) {
+        for (pubkey, watched_account) in self.accounts.iter_mut() {
+            // Accounts that do not exist are observed as zero-lamport system accounts
+            let account = root_bank.get_account(pubkey).unwrap_or_default();
+            let rent_exempt_reserve =
+                root_bank.get_minimum_balance_for_rent_exemption(account.data().len());
+            let change = watched_account.observe(&account, rent_exempt_reserve);
+            if let Some((owner, data_len)) = change {
+                self.changed = true;
+                warn!(
+                    "watched account {pubkey} changed at slot {}: owner {owner} -> {}, \
+                     data size {data_len} -> {}",
+                    root_bank.slot(),
+                    account.owner(),
+                    account.data().len(),
+                );
+            }
+        }
+    }
+
+    fn report(&mut self, root: Slot) {
+        let interval_elapsed = self.last_report_slot.map_or(true, |last_slot| {
+            root.saturating_sub(last_slot) >= WATCHED_ACCOUNTS_REPORT_INTERVAL_SLOTS
+        });
+        if !interval_elapsed && !self.changed {
+            return;
+        }
+        self.last_report_slot = Some(root);
+        self.changed = false;
+
+        for (pubkey, watched_account) in &self.accounts {
+            let Some((owner, data_len)) = watched_account.owner_and_data_len else {
+                continue;
+            };
+            datapoint_info!(
+                "epoch_metrics-watched_account",
+                ("root", root, i64),
+                ("pubkey", pubkey.to_string(), String),
+                ("lamports", watched_account.lamports, i64),
+                ("withdrawable", watched_account.withdrawable, i64),
+                ("owner", owner.to_string(), String),
+                ("data_len", data_len, i64),
+                ("changes", watched_account.changes, i64),
+            );
+        }
+    }
+}
+
+impl WatchedAccount {
+    /// Returns the previous owner and data size if either changed
+    fn observe(
+        &mut self,
+        account: &AccountSharedData,
+        rent_exempt_reserve: u64,
+    ) -> Option<(Pubkey, usize)> {
+        self.lamports = account.lamports();
+        self.withdrawable = account.lamports().saturating_sub(rent_exempt_reserve);
+        let owner_and_data_len = (*account.owner(), account.data().len());
+        let previous = self.owner_and_data_len.replace(owner_and_data_len)?;
+        if previous == owner_and_data_len {
+            return None;
+        }
+        self.changes += 1;
+        Some(previous)
+    }
+}
+
+/// Deployments of the configured upgradeable programs as of the latest root, and how many times
+/// their program data changed since the validator started.
+struct WatchedPrograms {
+    programs: HashMap<Pubkey, WatchedProgram>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct WatchedProgram {
+    observed: bool,
+    /// `None` if the program does not exist or is not an upgradeable program
+    deployment: Option<ProgramDeployment>,
+    changes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProgramDeployment {
+    slot: Slot,
+    upgrade_authority: Option<Pubkey>,
+}
+
+impl WatchedPrograms {
+    fn new(program_ids: HashSet<Pubkey>) -> Self {
+        Self {
+            programs: program_ids
+                .into_iter()
+                .map(|program_id| (program_id, WatchedProgram::default()))
+                .collect(),
+        }
+    }
+
+    fn update(&mut self, root_bank: &Bank) {
+        for (program_id, watched_program) in self.programs.iter_mut() {
+            let deployment = program_deployment(root_bank, program_id);
+            if let Some(previous) = watched_program.observe(deployment) {
+                warn!(
+                    "watched program {program_id} changed at slot {}: {previous:?} -> {deployment:?}",
+                    root_bank.slot(),
+                );
+            }
+        }
+    }
+
+    fn report(&self, root: Slot) {
+        for (program_id, watched_program) in &self.programs {
+            let Some(deployment) = watched_program.deployment else {
+                continue;
+            };
+            datapoint_info!(
+                "epoch_metrics-watched_program",
+                ("root", root, i64),
+                ("program_id", program_id.to_string(), String),
+                ("last_deployed_slot", deployment.slot, i64),
+                (
+                    "upgrade_authority",
+                    deployment.upgrade_authority.map(|pubkey| pubkey.to_string()),
+                    Option<String>
+                ),
+                ("changes", watched_program.changes, i64),
+            );
+        }
+    }
+}
+
+impl WatchedProgram {
+    /// Returns the previous deployment if the program data changed
+    fn observe(
+        &mut self,
+        deployment: Option<ProgramDeployment>,
+    ) -> Option<Option<ProgramDeployment>> {
+        let previous = mem::replace(&mut self.deployment, deployment);
+        if !mem::replace(&mut self.observed, true) || previous == deployment {
+            return None;
+        }
+        self.changes += 1;
+        Some(previous)
+    }
+}
+
+/// Every deploy, upgrade and authority change rewrites the program data header, so it is enough
+/// to identify a deployment without hashing the whole program
+fn program_deployment(bank: &Bank, program_id: &Pubkey) -> Option<ProgramDeployment> {
+    let program = bank.get_account(program_id)?;
+    if !bpf_loader_upgradeable::check_id(program.owner()) {
+        return None;
+    }
+    let Ok(UpgradeableLoaderState::Program {
+        programdata_address,
+    }) = program.state()
+    else {
+        return None;
+    };
+    let programdata = bank.get_account(&programdata_address)?;
+    let Ok(UpgradeableLoaderState::ProgramData {
+        slot,
+        upgrade_authority_address,
+    }) = programdata.state()
+    else {
+        return None;
+    };
+    Some(ProgramDeployment {
+        slot,
+        upgrade_authority: upgrade_authority_address,
+    })
+}
+
+fn latest_snapshot_hash(snapshot_hashes: &SnapshotHashes) -> (Slot, Hash) {
+    snapshot_hashes
+        .incremental
+        .iter()
+        .max_by_key(|(slot, _)| *slot)
+        .copied()
+        .unwrap_or(snapshot_hashes.full)
+}
+
+/// Of the given advertised snapshot hashes, how many include a hash for the slot of
+/// `snapshot_hash`, and how many of those agree with it
+fn snapshot_hash_agreement(
+    (slot, hash): (Slot, Hash),
+    snapshot_hashes: &[SnapshotHashes],
+) -> (usize, usize) {
+    snapshot_hashes
+        .iter()
+        .filter_map(|snapshot_hashes| {
+            std::iter::once(&snapshot_hashes.full)
+                .chain(&snapshot_hashes.incremental)
+                .find(|(other_slot, _)| *other_slot == slot)
+        })
+        .fold((0, 0), |(num_at_slot, num_matching), (_, other_hash)| {
+            (
+                num_at_slot + 1,
+                num_matching + usize::from(*other_hash == hash),
+            )
+        })
+}
+
+/// Credits earned during `epoch`, given a vote account's `(epoch, credits, prev_credits)` history
+fn credits_earned_in_epoch(epoch_credits: &[(Epoch, u64, u64)], epoch: Epoch) -> u64 {
+    epoch_credits
+        .iter()
+        .rev()
+        .find(|(credits_epoch, _, _)| *credits_epoch == epoch)
+        .map(|(_, credits, prev_credits)| credits.saturating_sub(*prev_credits))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::vote::state::{LandedVote, Lockout},
+    };
+
+    #[test]
+    fn test_credits_earned_in_epoch() {
+        let epoch_credits = vec![(3, 100, 0), (4, 250, 100), (6, 300, 250)];
+        assert_eq!(credits_earned_in_epoch(&epoch_credits, 3), 100);
+        assert_eq!(credits_earned_in_epoch(&epoch_credits, 4), 150);
+        assert_eq!(credits_earned_in_epoch(&epoch_credits, 5), 0);
+        assert_eq!(credits_earned_in_epoch(&epoch_credits, 6), 50);
+        assert_eq!(credits_earned_in_epoch(&[], 6), 0);
+    }
+
+    #[test]
+    fn test_landing_slots() {
+        let mut vote_state = VoteState::default();
+        // a vote transaction for slots 10 and 11 landed in slot 12, one for slot 13 in slot 15
+        vote_state.votes = [(10, 2), (11, 1), (13, 2), (14, 0), (15, u8::MAX)]
+            .into_iter()
+            .map(|(slot, latency)| LandedVote {
+                latency,
+                lockout: Lockout::new(slot),
+            })
+            .collect();
+        assert_eq!(landing_slots(&vote_state), BTreeSet::from([12, 15]));
+    }
+
+    #[test]
+    fn test_vote_inclusion_by_leader_record() {
+        let leader_0 = Pubkey::new_unique();
+        let leader_1 = Pubkey::new_unique();
+        let mut vote_inclusion_by_leader = VoteInclusionByLeader::default();
+
+        vote_inclusion_by_leader.record_block(leader_0);
+        vote_inclusion_by_leader.record_block(leader_0);
+        vote_inclusion_by_leader.record_landed_vote(leader_0);
+        vote_inclusion_by_leader.record_block(leader_1);
+        assert_eq!(
+            vote_inclusion_by_leader.leaders[&leader_0],
+            LeaderVoteInclusion {
+                blocks: 2,
+                landed_votes: 1,
+            }
+        );
+        assert_eq!(
+            vote_inclusion_by_leader.leaders[&leader_1],
+            LeaderVoteInclusion {
+                blocks: 1,
+                landed_votes: 0,
+            }
+        );
+
+        vote_inclusion_by_leader.report();
+        assert!(vote_inclusion_by_leader.updated_leaders.is_empty());
+        vote_inclusion_by_leader.record_landed_vote(leader_1);
+        assert_eq!(
+            vote_inclusion_by_leader.updated_leaders,
+            HashSet::from([leader_1])
+        );
+    }
+
+    #[test]
+    fn test_changed_commissions() {
+        let vote_account_0 = Pubkey::new_unique();
+        let vote_account_1 = Pubkey::new_unique();
+        let vote_account_2 = Pubkey::new_unique();
+        let before = HashMap::from([(vote_account_0, 5), (vote_account_1, 10)]);
+        let after = HashMap::from([
+            (vote_account_0, 100),
+            (vote_account_1, 10),
+            (vote_account_2, 0),
+        ]);
+
+        assert_eq!(
+            changed_commissions(&before, &after),
+            vec![(vote_account_0, 5, 100)]
+        );
+        assert_eq!(changed_commissions(&after, &after), vec![]);
+    }
+
+    #[test]
+    fn test_watched_account_observe() {
+        let owner = Pubkey::new_unique();
+        let mut watched_account = WatchedAccount::default();
+
+        assert_eq!(
+            watched_account.observe(&AccountSharedData::new(10, 8, &owner), 5),
+            None
+        );
+        // lamport changes alone are not counted
+        assert_eq!(
+            watched_account.observe(&AccountSharedData::new(20, 8, &owner), 5),
+            None
+        );
+        assert_eq!(watched_account.changes, 0);
+
+        let new_owner = Pubkey::new_unique();
+        assert_eq!(
+            watched_account.observe(&AccountSharedData::new(20, 8, &new_owner), 5),
+            Some((owner, 8))
+        );
+        assert_eq!(
+            watched_account.observe(&AccountSharedData::new(20, 16, &new_owner), 5),
+            Some((new_owner, 8))
+        );
+        assert_eq!(
+            watched_account,
+            WatchedAccount {
+                lamports: 20,
+                withdrawable: 15,
+                owner_and_data_len: Some((new_owner, 16)),
+                changes: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_watched_program_observe() {
+        let deployment = ProgramDeployment {
+            slot: 5,
+            upgrade_authority: Some(Pubkey::new_unique()),
+        };
+        let upgraded = ProgramDeployment {
+            slot: 9,
+            ..deployment
+        };
+        let mut watched_program = WatchedProgram::default();
+
+        assert_eq!(watched_program.observe(Some(deployment)), None);
+        assert_eq!(watched_program.observe(Some(deployment)), None);
+        assert_eq!(
+            watched_program.observe(Some(upgraded)),
+            Some(Some(deployment))
+        );
+        // closing the program is a change too
+        assert_eq!(watched_program.observe(None), Some(Some(upgraded)));
+        assert_eq!(watched_program.changes, 2);
+
+        // a program that does not exist yet is only counted once deployed
+        let mut watched_program = WatchedProgram::default();
+        assert_eq!(watched_program.observe(None), None);
+        assert_eq!(watched_program.observe(Some(deployment)), Some(None));
+        assert_eq!(watched_program.changes, 1);
+    }
+
+    #[test]
+    fn test_snapshot_hash_agreement() {
+        let snapshot_hashes = |full: (Slot, Hash), incremental: Vec<(Slot, Hash)>| SnapshotHashes {
+            from: Pubkey::new_unique(),
+            full,
+            incremental,
+            wallclock: 0,
+        };
+        let hash = Hash::new_unique();
+        let other_hash = Hash::new_unique();
+
+        let ours = snapshot_hashes((100, Hash::new_unique()), vec![(150, hash)]);
+        assert_eq!(latest_snapshot_hash(&ours), (150, hash));
+        assert_eq!(
+            latest_snapshot_hash(&snapshot_hashes((100, hash), vec![])),
+            (100, hash)
+        );
+
+        let known_validators_snapshot_hashes = vec![
+            snapshot_hashes((100, Hash::new_unique()), vec![(150, hash)]),
+            snapshot_hashes((150, hash), vec![]),
+            snapshot_hashes((100, Hash::new_unique()), vec![(150, other_hash)]),
+            // not yet at our slot
+            snapshot_hashes((100, Hash::new_unique()), vec![(140, Hash::new_unique())]),
+        ];
+        assert_eq!(
+            snapshot_hash_agreement((150, hash), &known_validators_snapshot_hashes),
+            (3, 2)
+        );
+    }
+
+    #[test]
+    fn test_known_validators_tip_record_vote() {
+        let known_validator_0 = Pubkey::new_unique();
+        let known_validator_1 = Pubkey::new_unique();
+        let mut known_validators_tip =
+            KnownValidatorsTip::new(HashSet::from([known_validator_0, known_validator_1]));
+        assert_eq!(known_validators_tip.max_voted_slot(), None);
+
+        known_validators_tip.record_vote(known_validator_0, 10);
+        known_validators_tip.record_vote(known_validator_1, 12);
+        // gossip votes may arrive out of order
+        known_validators_tip.record_vote(known_validator_1, 11);
+        assert_eq!(known_validators_tip.voted_slots[&known_validator_1], 12);
+        assert_eq!(known_validators_tip.max_voted_slot(), Some(12));
+    }
+
+    #[test]
+    fn test_vote_credit_loss_attribution() {
+        let mut vote_credit_loss = VoteCreditLoss {
+            max_credits_per_slot: 8,
+            ..VoteCreditLoss::default()
+        };
+
+        vote_credit_loss.observe_tower(vec![(10, 8), (11, 5), (12, 8)], 9);
+        // slot 12 expired after switching to the fork containing slot 13
+        vote_credit_loss.observe_tower(vec![(10, 8), (11, 5), (13, 8)], 9);
+        assert_eq!(vote_credit_loss.expired_votes, BTreeSet::from([12]));
+
+        for slot in [10, 11, 12, 14] {
+            vote_credit_loss.attribute_rooted_block(slot);
+        }
+        assert_eq!(vote_credit_loss.rooted_blocks, 4);
+        assert_eq!(vote_credit_loss.late_vote_loss, 3);
+        assert_eq!(vote_credit_loss.lockout_loss, 8);
+        assert_eq!(vote_credit_loss.missed_vote_loss, 8);
+        assert_eq!(
+            vote_credit_loss.landed_vote_credits,
+            BTreeMap::from([(13, 8)])
+        );
+    }
+
+    #[test]
+    fn test_skip_rate_record_slot() {
+        let mut skip_rate = SkipRate::default();
+        assert_eq!(skip_rate.cluster_skip_rate(), None);
+        assert_eq!(skip_rate.own_skip_rate(), None);
+
+        skip_rate.record_slot(false, false);
+        skip_rate.record_slot(false, true);
+        skip_rate.record_slot(true, false);
+        skip_rate.record_slot(false, false);
+        assert_eq!(skip_rate.cluster_skip_rate(), Some(0.25));
+        assert_eq!(skip_rate.own_skip_rate(), Some(0.0));
+
+        skip_rate.record_slot(true, true);
+        assert_eq!(skip_rate.leader_slots, 5);
+        assert_eq!(skip_rate.skipped_slots, 2);
+        assert_eq!(skip_rate.own_leader_slots, 2);
+        assert_eq!(skip_rate.own_skipped_slots, 1);
+        assert_eq!(skip_rate.own_skip_rate(), Some(0.5));
+    }
+}
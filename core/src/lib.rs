@@ -22,6 +22,7 @@ pub mod consensus;
 pub mod consensus_cache_updater;
 pub mod cost_update_service;
 pub mod drop_bank_service;
+pub mod epoch_metrics_service;
 pub mod fetch_stage;
 pub mod gen_keys;
 pub mod immutable_deserialized_bundle;
@@ -101,6 +101,9 @@ const MAX_VOTE_REFRESH_INTERVAL_MILLIS: usize = 5000;
 // to be able to replay all active forks at the same time in most cases.
 const MAX_CONCURRENT_FORKS_TO_REPLAY: usize = 4;
 const MAX_REPAIR_RETRY_LOOP_ATTEMPTS: usize = 10;
+// Our own working bank is reported as stalled once it has been open for this many slot times
+const STALLED_LEADER_BANK_SLOT_TIMES: u128 = 2;
+const STALLED_LEADER_BANK_REPORT_INTERVAL_MS: u128 = 1000;
 
 lazy_static! {
     static ref PAR_THREAD_POOL: ThreadPool = rayon::ThreadPoolBuilder::new()
@@ -593,6 +596,7 @@ impl ReplayStage {
                 last_refresh_time: Instant::now(),
                 last_print_time: Instant::now(),
             };
+            let mut last_stalled_leader_bank_report = Instant::now();
             let (working_bank, in_vote_only_mode) = {
                 let r_bank_forks = bank_forks.read().unwrap();
                 (
@@ -628,6 +632,12 @@ impl ReplayStage {
                 generate_new_bank_forks_time.stop();
 
                 let mut tpu_has_bank = poh_recorder.read().unwrap().has_bank();
+                if tpu_has_bank {
+                    Self::report_stalled_leader_bank(
+                        &poh_recorder,
+                        &mut last_stalled_leader_bank_report,
+                    );
+                }
 
                 let mut replay_active_banks_time = Measure::start("replay_active_banks_time");
                 let mut ancestors = bank_forks.read().unwrap().ancestors();
@@ -786,6 +796,7 @@ impl ReplayStage {
                 );
                 compute_bank_stats_time.stop();
 
+                let computed_new_bank_stats = !newly_computed_slot_stats.is_empty();
                 let mut compute_slot_stats_time = Measure::start("compute_slot_stats_time");
                 for slot in newly_computed_slot_stats {
                     let fork_stats = progress.get_fork_stats(slot).unwrap();
@@ -824,6 +835,15 @@ impl ReplayStage {
                     );
                 select_forks_time.stop();
 
+                if computed_new_bank_stats {
+                    Self::report_last_vote_fork_weight(
+                        &tower,
+                        &heaviest_subtree_fork_choice,
+                        &progress,
+                        heaviest_bank.slot(),
+                    );
+                }
+
                 Self::check_for_vote_only_mode(
                     heaviest_bank.slot(),
                     forks_root,
@@ -1215,6 +1235,81 @@ impl ReplayStage {
         }
     }
 
+    /// Reports the age of our own working bank if it has been open for longer than expected,
+    /// an early sign of a leader stall on this node
+    fn report_stalled_leader_bank(
+        poh_recorder: &RwLock<PohRecorder>,
+        last_stalled_leader_bank_report: &mut Instant,
+    ) {
+        let Some(bank_start) = poh_recorder.read().unwrap().bank_start() else {
+            return;
+        };
+        let bank = &bank_start.working_bank;
+        let age = bank_start.bank_creation_time.elapsed();
+        let stall_threshold_nanos = bank
+            .ns_per_slot
+            .saturating_mul(STALLED_LEADER_BANK_SLOT_TIMES);
+        if bank.is_frozen()
+            || age.as_nanos() <= stall_threshold_nanos
+            || last_stalled_leader_bank_report.elapsed().as_millis()
+                < STALLED_LEADER_BANK_REPORT_INTERVAL_MS
+        {
+            return;
+        }
+        *last_stalled_leader_bank_report = Instant::now();
+        datapoint_warn!(
+            "replay_stage-stalled_leader_bank",
+            ("slot", bank.slot(), i64),
+            ("age_ms", age.as_millis() as i64, i64),
+            ("expected_ms", (bank.ns_per_slot / 1_000_000) as i64, i64),
+            ("tick_height", bank.tick_height(), i64),
+            ("max_tick_height", bank.max_tick_height(), i64),
+        );
+    }
+
+    /// Reports the stake voted on the fork of our last vote against the stake voted on
+    /// competing forks, to show when this node keeps voting on a minority fork
+    fn report_last_vote_fork_weight(
+        tower: &Tower,
+        heaviest_subtree_fork_choice: &HeaviestSubtreeForkChoice,
+        progress: &ProgressMap,
+        heaviest_slot: Slot,
+    ) {
+        let Some(last_vote) = tower.last_voted_slot_hash() else {
+            return;
+        };
+        let (Some(last_vote_fork_stake), Some(competing_forks_stake)) = (
+            heaviest_subtree_fork_choice.stake_voted_subtree(&last_vote),
+            heaviest_subtree_fork_choice.stake_voted_on_competing_forks(&last_vote),
+        ) else {
+            return;
+        };
+        let Some(total_stake) = progress
+            .get_fork_stats(heaviest_slot)
+            .map(|fork_stats| fork_stats.total_stake)
+            .filter(|total_stake| *total_stake > 0)
+        else {
+            return;
+        };
+        datapoint_info!(
+            "replay_stage-last_vote_fork_weight",
+            ("last_voted_slot", last_vote.0, i64),
+            ("heaviest_slot", heaviest_slot, i64),
+            ("last_vote_fork_stake", last_vote_fork_stake, i64),
+            ("competing_forks_stake", competing_forks_stake, i64),
+            (
+                "last_vote_fork_weight",
+                last_vote_fork_stake as f64 / total_stake as f64,
+                f64
+            ),
+            (
+                "competing_forks_weight",
+                competing_forks_stake as f64 / total_stake as f64,
+                f64
+            ),
+        );
+    }
+
     fn retransmit_latest_unpropagated_leader_slot(
         poh_recorder: &Arc<RwLock<PohRecorder>>,
         retransmit_slots_sender: &Sender<Slot>,
@@ -2917,10 +3012,18 @@ impl ReplayStage {
                     transaction_status_sender.send_transaction_status_freeze_message(bank);
                 }
                 bank.freeze();
+                let (blockhash_queue_len, oldest_valid_blockhash_age_ms) =
+                    bank.blockhash_queue_stats();
                 datapoint_info!(
                     "bank_frozen",
                     ("slot", bank_slot, i64),
                     ("hash", bank.hash().to_string(), String),
+                    ("blockhash_queue_len", blockhash_queue_len, i64),
+                    (
+                        "oldest_valid_blockhash_age_ms",
+                        oldest_valid_blockhash_age_ms,
+                        Option<i64>
+                    ),
                 );
                 // report cost tracker stats
                 cost_update_sender
@@ -3311,11 +3414,18 @@ impl ReplayStage {
                     stats.my_latest_landed_vote = my_latest_landed_vote;
                     stats.computed = true;
                     new_stats.push(bank_slot);
+                    let last_voted_slot = tower.last_voted_slot();
+                    let unlanded_vote_gap = last_voted_slot.map(|last_voted_slot| {
+                        last_voted_slot.saturating_sub(my_latest_landed_vote.unwrap_or_default())
+                    });
                     datapoint_info!(
                         "bank_weight",
                         ("slot", bank_slot, i64),
                         ("fork_stake", stats.fork_stake, i64),
                         ("fork_weight", stats.fork_weight(), f64),
+                        ("last_voted_slot", last_voted_slot, Option<i64>),
+                        ("my_latest_landed_vote", my_latest_landed_vote, Option<i64>),
+                        ("unlanded_vote_gap", unlanded_vote_gap, Option<i64>),
                     );
 
                     info!(
@@ -23,7 +23,7 @@ use {
             atomic::{AtomicBool, Ordering},
             Arc, RwLock,
         },
-        thread::{self, Builder, JoinHandle},
+        thread::{self, sleep, Builder, JoinHandle},
         time::{Duration, Instant},
     },
 };
@@ -154,6 +154,7 @@ impl ShredFetchStage {
         bank_forks: Arc<RwLock<BankForks>>,
         shred_version: u16,
         name: &'static str,
+        receive_stats: Arc<StreamerReceiveStats>,
         flags: PacketFlags,
         repair_context: Option<(Arc<UdpSocket>, Arc<ClusterInfo>)>,
         turbine_disabled: Arc<AtomicBool>,
@@ -167,7 +168,7 @@ impl ShredFetchStage {
                     exit.clone(),
                     packet_sender.clone(),
                     recycler.clone(),
-                    Arc::new(StreamerReceiveStats::new("packet_modifier")),
+                    receive_stats.clone(),
                     PACKET_COALESCE_DURATION,
                     true, // use_pinned_memory
                     None, // in_vote_only_mode
@@ -209,6 +210,8 @@ impl ShredFetchStage {
         exit: Arc<AtomicBool>,
     ) -> Self {
         let recycler = PacketBatchRecycler::warmed(100, 1024);
+        let tvu_stats = Arc::new(StreamerReceiveStats::new("shred_fetch_receiver"));
+        let repair_stats = Arc::new(StreamerReceiveStats::new("shred_fetch_repair_receiver"));
 
         let (mut tvu_threads, tvu_filter) = Self::packet_modifier(
             sockets,
@@ -218,6 +221,7 @@ impl ShredFetchStage {
             bank_forks.clone(),
             shred_version,
             "shred_fetch",
+            tvu_stats.clone(),
             PacketFlags::empty(),
             None, // repair_context
             turbine_disabled.clone(),
@@ -231,6 +235,7 @@ impl ShredFetchStage {
             bank_forks.clone(),
             shred_version,
             "shred_fetch_repair",
+            repair_stats.clone(),
             PacketFlags::REPAIR,
             Some((repair_socket, cluster_info)),
             turbine_disabled.clone(),
@@ -239,6 +244,25 @@ impl ShredFetchStage {
         tvu_threads.extend(repair_receiver);
         tvu_threads.push(tvu_filter);
         tvu_threads.push(repair_handler);
+        // Reported from their own thread since reporting reads the kernel's socket tables.
+        {
+            let exit = exit.clone();
+            tvu_threads.push(
+                Builder::new()
+                    .name("solTvuFetchMetr".to_string())
+                    .spawn(move || loop {
+                        sleep(Duration::from_secs(1));
+
+                        tvu_stats.report();
+                        repair_stats.report();
+
+                        if exit.load(Ordering::Relaxed) {
+                            return;
+                        }
+                    })
+                    .unwrap(),
+            );
+        }
         // Repair shreds fetched over QUIC protocol.
         {
             let (packet_sender, packet_receiver) = unbounded();
@@ -16,7 +16,7 @@ use {
             Arc,
         },
         thread::{self, sleep, Builder, JoinHandle},
-        time::Duration,
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
     },
     sys_info::{Error, LoadAvg},
 };
@@ -30,6 +30,7 @@ const SAMPLE_INTERVAL_MEM_MS: u64 = 5 * MS_PER_S;
 const SAMPLE_INTERVAL_CPU_MS: u64 = 10 * MS_PER_S;
 const SAMPLE_INTERVAL_CPU_ID_MS: u64 = MS_PER_H;
 const SAMPLE_INTERVAL_DISK_MS: u64 = 5 * MS_PER_S;
+const SAMPLE_INTERVAL_UPTIME_MS: u64 = 10 * MS_PER_S;
 const SLEEP_INTERVAL: Duration = Duration::from_millis(500);
 
 #[cfg(target_os = "linux")]
@@ -964,7 +965,21 @@ impl SystemMonitorService {
         )
     }
 
+    fn report_uptime(start_time: SystemTime, start: Instant) {
+        let start_time_secs = start_time
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        datapoint_info!(
+            "process-uptime",
+            ("start_time_secs", start_time_secs, i64),
+            ("uptime_secs", start.elapsed().as_secs(), i64),
+        );
+    }
+
     pub fn run(exit: Arc<AtomicBool>, config: SystemMonitorStatsReportConfig) {
+        let start_time = SystemTime::now();
+        let start = Instant::now();
         let mut udp_stats = None;
         let mut disk_stats = None;
         let network_limits_timer = AtomicInterval::default();
@@ -973,6 +988,7 @@ impl SystemMonitorService {
         let cpu_timer = AtomicInterval::default();
         let cpuid_timer = AtomicInterval::default();
         let disk_timer = AtomicInterval::default();
+        let uptime_timer = AtomicInterval::default();
 
         loop {
             if exit.load(Ordering::Relaxed) {
@@ -1001,6 +1017,9 @@ impl SystemMonitorService {
             if config.report_os_disk_stats && disk_timer.should_update(SAMPLE_INTERVAL_DISK_MS) {
                 Self::process_disk_stats(&mut disk_stats);
             }
+            if uptime_timer.should_update(SAMPLE_INTERVAL_UPTIME_MS) {
+                Self::report_uptime(start_time, start);
+            }
             sleep(SLEEP_INTERVAL);
         }
     }
@@ -14,6 +14,7 @@ use {
         consensus::{tower_storage::TowerStorage, Tower},
         cost_update_service::CostUpdateService,
         drop_bank_service::DropBankService,
+        epoch_metrics_service::EpochMetricsService,
         repair::{
             quic_endpoint::LocalRequest,
             repair_service::{OutstandingShredRepairs, RepairInfo},
@@ -72,6 +73,7 @@ pub struct Tvu {
     warm_quic_cache_service: Option<WarmQuicCacheService>,
     drop_bank_service: DropBankService,
     duplicate_shred_listener: DuplicateShredListener,
+    epoch_metrics_service: EpochMetricsService,
 }
 
 pub struct TvuSockets {
@@ -89,6 +91,13 @@ pub struct TvuConfig {
     pub repair_validators: Option<HashSet<Pubkey>>,
     // Validators which should be given priority when serving repairs
     pub repair_whitelist: Arc<RwLock<HashSet<Pubkey>>>,
+    // Validators whose votes are trusted to report the tip of the cluster
+    pub known_validators: Option<HashSet<Pubkey>>,
+    // Accounts whose lamports, owner and data size are reported, including our vote account if
+    // voting
+    pub watched_accounts: HashSet<Pubkey>,
+    // Upgradeable programs whose deployments are reported
+    pub watched_programs: HashSet<Pubkey>,
     pub wait_for_vote_to_start_leader: bool,
     pub replay_slots_concurrently: bool,
 }
@@ -249,13 +258,14 @@ impl Tvu {
         );
 
         let (blockstore_cleanup_slot_sender, blockstore_cleanup_slot_receiver) = unbounded();
+        let (epoch_metrics_root_sender, epoch_metrics_root_receiver) = unbounded();
         let replay_stage_config = ReplayStageConfig {
             vote_account: *vote_account,
             authorized_voter_keypairs,
             exit: exit.clone(),
             rpc_subscriptions: rpc_subscriptions.clone(),
             leader_schedule_cache: leader_schedule_cache.clone(),
-            latest_root_senders: vec![blockstore_cleanup_slot_sender],
+            latest_root_senders: vec![blockstore_cleanup_slot_sender, epoch_metrics_root_sender],
             accounts_background_request_sender,
             block_commitment_cache,
             transaction_status_sender,
@@ -295,6 +305,17 @@ impl Tvu {
 
         let drop_bank_service = DropBankService::new(drop_bank_receiver);
 
+        let epoch_metrics_service = EpochMetricsService::new(
+            bank_forks.clone(),
+            cluster_info.clone(),
+            leader_schedule_cache.clone(),
+            *vote_account,
+            tvu_config.known_validators,
+            tvu_config.watched_accounts,
+            tvu_config.watched_programs,
+            epoch_metrics_root_receiver,
+        );
+
         let replay_stage = ReplayStage::new(
             replay_stage_config,
             blockstore.clone(),
@@ -356,6 +377,7 @@ impl Tvu {
             warm_quic_cache_service,
             drop_bank_service,
             duplicate_shred_listener,
+            epoch_metrics_service,
         })
     }
 
@@ -376,6 +398,7 @@ impl Tvu {
         }
         self.drop_bank_service.join()?;
         self.duplicate_shred_listener.join()?;
+        self.epoch_metrics_service.join()?;
         Ok(())
     }
 }
@@ -68,7 +68,8 @@ use {
     },
     solana_measure::measure::Measure,
     solana_metrics::{
-        datapoint_info, metrics::metrics_config_sanity_check, poh_timing_point::PohTimingSender,
+        datapoint_info, inc_new_counter_info, metrics::metrics_config_sanity_check,
+        poh_timing_point::PohTimingSender,
     },
     solana_poh::{
         poh_recorder::PohRecorder,
@@ -129,6 +130,7 @@ use {
     solana_wen_restart::wen_restart::wait_for_wen_restart,
     std::{
         collections::{HashMap, HashSet},
+        fs,
         net::SocketAddr,
         path::{Path, PathBuf},
         sync::{
@@ -145,6 +147,9 @@ use {
 
 const MAX_COMPLETED_DATA_SETS_IN_CHANNEL: usize = 100_000;
 const WAIT_FOR_SUPERMAJORITY_THRESHOLD_PERCENT: u64 = 80;
+// Created in the ledger directory at startup and removed when exit is requested, so finding it
+// at startup means the previous run did not shut down cleanly
+const RUNNING_MARKER_FILE: &str = "validator-running";
 
 #[derive(Clone, EnumString, EnumVariantNames, Default, IntoStaticStr, Display)]
 #[strum(serialize_all = "kebab-case")]
@@ -229,6 +234,8 @@ pub struct ValidatorConfig {
     pub repair_validators: Option<HashSet<Pubkey>>, // None = repair from all
     pub repair_whitelist: Arc<RwLock<HashSet<Pubkey>>>, // Empty = repair with all
     pub gossip_validators: Option<HashSet<Pubkey>>, // None = gossip with all
+    pub watched_accounts: HashSet<Pubkey>,
+    pub watched_programs: HashSet<Pubkey>,
     pub accounts_hash_interval_slots: u64,
     pub max_genesis_archive_unpacked_size: u64,
     pub wal_recovery_mode: Option<BlockstoreRecoveryMode>,
@@ -305,6 +312,8 @@ impl Default for ValidatorConfig {
             repair_validators: None,
             repair_whitelist: Arc::new(RwLock::new(HashSet::default())),
             gossip_validators: None,
+            watched_accounts: HashSet::default(),
+            watched_programs: HashSet::default(),
             accounts_hash_interval_slots: std::u64::MAX,
             max_genesis_archive_unpacked_size: MAX_GENESIS_ARCHIVE_UNPACKED_SIZE,
             wal_recovery_mode: None,
@@ -589,6 +598,12 @@ impl Validator {
             open_genesis_config(ledger_path, config.max_genesis_archive_unpacked_size)
                 .map_err(|err| format!("Failed to open genesis config: {err}"))?;
 
+        let running_marker_path = ledger_path.join(RUNNING_MARKER_FILE);
+        if running_marker_path.exists() {
+            warn!("The previous validator run did not shut down cleanly");
+            inc_new_counter_info!("validator-unclean_shutdown", 1);
+        }
+
         metrics_config_sanity_check(genesis_config.cluster_type)?;
 
         if let Some(expected_shred_version) = config.expected_shred_version {
@@ -658,6 +673,23 @@ impl Validator {
                 .unwrap()
                 .register_exit(Box::new(move || exit.store(true, Ordering::Relaxed)));
         }
+        // A requested exit counts as a clean shutdown, even if the process is later killed
+        // because the services did not stop in time
+        {
+            let running_marker_path = running_marker_path.clone();
+            config
+                .validator_exit
+                .write()
+                .unwrap()
+                .register_exit(Box::new(move || {
+                    // The marker is not created yet if startup has not finished
+                    if let Err(err) = fs::remove_file(&running_marker_path) {
+                        if err.kind() != std::io::ErrorKind::NotFound {
+                            warn!("Failed to remove {running_marker_path:?}: {err}");
+                        }
+                    }
+                }));
+        }
 
         let accounts_update_notifier = geyser_plugin_service
             .as_ref()
@@ -832,7 +864,8 @@ impl Validator {
 
         // block min prioritization fee cache should be readable by RPC, and writable by validator
         // (by both replay stage and banking stage)
-        let prioritization_fee_cache = Arc::new(PrioritizationFeeCache::default());
+        let prioritization_fee_cache =
+            Arc::new(PrioritizationFeeCache::new_with_leader_identity(id));
 
         match &config.block_verification_method {
             BlockVerificationMethod::BlockstoreProcessor => {
@@ -1320,6 +1353,19 @@ impl Validator {
                 shred_version: node.info.shred_version(),
                 repair_validators: config.repair_validators.clone(),
                 repair_whitelist: config.repair_whitelist.clone(),
+                known_validators: config.known_validators.clone(),
+                watched_accounts: if config.voting_disabled {
+                    config.watched_accounts.clone()
+                } else {
+                    // report the commission that can be withdrawn from our vote account
+                    config
+                        .watched_accounts
+                        .iter()
+                        .copied()
+                        .chain([*vote_account])
+                        .collect()
+                },
+                watched_programs: config.watched_programs.clone(),
                 wait_for_vote_to_start_leader,
                 replay_slots_concurrently: config.replay_slots_concurrently,
             },
@@ -1402,6 +1448,12 @@ impl Validator {
             config.preallocated_bundle_cost,
         );
 
+        // Only mark the validator as running once startup has succeeded, so that a failed start
+        // is not reported as an unclean shutdown by the next one
+        if let Err(err) = fs::File::create(&running_marker_path) {
+            warn!("Failed to create {running_marker_path:?}: {err}");
+        }
+
         datapoint_info!(
             "validator-new",
             ("id", id.to_string(), String),
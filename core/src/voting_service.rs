@@ -88,12 +88,22 @@ impl VotingService {
             VoteOp::PushVote {
                 tx, tower_slots, ..
             } => {
+                datapoint_info!(
+                    "voting_service-vote_sent",
+                    ("vote_slot", tower_slots.last().copied(), Option<i64>),
+                    ("refresh", false, bool),
+                );
                 cluster_info.push_vote(&tower_slots, tx);
             }
             VoteOp::RefreshVote {
                 tx,
                 last_voted_slot,
             } => {
+                datapoint_info!(
+                    "voting_service-vote_sent",
+                    ("vote_slot", last_voted_slot, i64),
+                    ("refresh", true, bool),
+                );
                 cluster_info.refresh_vote(tx, last_voted_slot);
             }
         }
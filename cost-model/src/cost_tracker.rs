@@ -155,11 +155,14 @@ impl CostTracker {
             "cost_tracker_stats",
             ("bank_slot", bank_slot as i64, i64),
             ("block_cost", self.block_cost as i64, i64),
+            ("block_cost_limit", self.block_cost_limit as i64, i64),
             ("vote_cost", self.vote_cost as i64, i64),
+            ("vote_cost_limit", self.vote_cost_limit as i64, i64),
             ("transaction_count", self.transaction_count as i64, i64),
             ("number_of_accounts", self.number_of_accounts() as i64, i64),
             ("costliest_account", costliest_account.to_string(), String),
             ("costliest_account_cost", costliest_account_cost as i64, i64),
+            ("account_cost_limit", self.account_cost_limit as i64, i64),
             ("account_data_size", self.account_data_size, i64),
         );
     }
@@ -79,6 +79,10 @@ pub struct TransactionBatchWithIndexes<'a, 'b> {
     pub transaction_indexes: Vec<usize>,
 }
 
+/// The compute units consumed by each replayed transaction are only collected into
+/// `ExecuteTimings::transaction_compute_units` for one slot out of this many.
+pub const TRANSACTION_COMPUTE_UNITS_SAMPLE_INTERVAL_SLOTS: Slot = 10;
+
 struct ReplayEntry {
     entry: EntryType,
     starting_index: usize,
@@ -187,6 +191,17 @@ pub fn execute_batch(
         .filter_map(|(execution_result, tx)| execution_result.was_executed().then_some(tx))
         .collect_vec();
 
+    if bank.slot() % TRANSACTION_COMPUTE_UNITS_SAMPLE_INTERVAL_SLOTS == 0 {
+        for details in execution_results
+            .iter()
+            .filter_map(|result| result.details())
+        {
+            timings
+                .transaction_compute_units
+                .record(details.executed_units);
+        }
+    }
+
     if let Some(transaction_status_sender) = transaction_status_sender {
         let transactions = batch.sanitized_transactions().to_vec();
         let post_token_balances = if record_token_balances {
@@ -6,6 +6,7 @@ use {
     },
     itertools::Itertools,
     log::*,
+    solana_measure::measure_us,
     solana_runtime::bank::Bank,
     solana_sdk::{
         clock::{Epoch, Slot},
@@ -218,18 +219,27 @@ impl LeaderScheduleCache {
     }
 
     fn compute_epoch_schedule(&self, epoch: Epoch, bank: &Bank) -> Option<Arc<LeaderSchedule>> {
-        let leader_schedule = leader_schedule_utils::leader_schedule(epoch, bank);
+        let (leader_schedule, compute_us) =
+            measure_us!(leader_schedule_utils::leader_schedule(epoch, bank));
         leader_schedule.map(|leader_schedule| {
             let leader_schedule = Arc::new(leader_schedule);
             let (ref mut cached_schedules, ref mut order) = *self.cached_schedules.write().unwrap();
             // Check to see if schedule exists in case somebody already inserted in the time we were
             // waiting for the lock
             let entry = cached_schedules.entry(epoch);
+            let inserted = matches!(entry, Entry::Vacant(_));
             if let Entry::Vacant(v) = entry {
                 v.insert(leader_schedule.clone());
                 order.push_back(epoch);
                 Self::retain_latest(cached_schedules, order, self.max_schedules());
             }
+            datapoint_info!(
+                "leader_schedule_cache-compute_epoch_schedule",
+                ("epoch", epoch, i64),
+                ("bank_slot", bank.slot(), i64),
+                ("compute_us", compute_us, i64),
+                ("inserted", inserted, bool),
+            );
             leader_schedule
         })
     }
@@ -1,7 +1,7 @@
 use {
     crate::{
         invoke_context::{BuiltinFunctionWithContext, InvokeContext},
-        timings::ExecuteDetailsTimings,
+        timings::{BucketHistogram, ExecuteDetailsTimings},
     },
     log::{debug, error, log_enabled, trace},
     percentage::PercentageInteger,
@@ -23,6 +23,7 @@ use {
     std::{
         collections::HashMap,
         fmt::{Debug, Formatter},
+        mem,
         sync::{
             atomic::{AtomicU64, Ordering},
             Arc, Condvar, Mutex, RwLock,
@@ -170,6 +171,8 @@ pub struct Stats {
     pub prunes_environment: AtomicU64,
     /// the [SecondLevel] was empty because all slot versions got pruned
     pub empty_entries: AtomicU64,
+    /// distribution of the time it took to JIT compile loaded programs
+    pub jit_compile_us: Mutex<BucketHistogram>,
 }
 
 impl Stats {
@@ -199,6 +202,9 @@ impl Stats {
             ("prunes_environment", prunes_environment, i64),
             ("empty_entries", empty_entries, i64),
         );
+        // Taken so that each datapoint only covers the compilations since the previous one
+        mem::take(&mut *self.jit_compile_us.lock().unwrap())
+            .report("loaded-programs-cache-jit-compile-us", slot);
         debug!(
             "Loaded Programs Cache Stats -- Hits: {}, Misses: {}, Evictions: {}, Insertions: {}, Replacements: {}, One-Hit-Wonders: {}, Prunes-Orphan: {}, Prunes-Expired: {}, Prunes-Environment: {}, Empty: {}",
             hits, misses, evictions, insertions, replacements, one_hit_wonders, prunes_orphan, prunes_expired, prunes_environment, empty_entries
@@ -1,6 +1,7 @@
 use {
     core::fmt,
     enum_iterator::Sequence,
+    solana_metrics::datapoint::DataPoint,
     solana_sdk::{clock::Slot, pubkey::Pubkey, saturating_add_assign},
     std::{
         collections::HashMap,
@@ -310,11 +311,84 @@ impl ThreadExecuteTimings {
     }
 }
 
+/// Upper bounds (inclusive) of the [`BucketHistogram`] buckets, on a 1-2-5 scale. Values above
+/// the last bound are counted in an additional overflow bucket.
+const BUCKET_HISTOGRAM_BOUNDS: [u64; 10] = [
+    1_000, 2_000, 5_000, 10_000, 20_000, 50_000, 100_000, 200_000, 500_000, 1_000_000,
+];
+
+/// Datapoint field of each [`BucketHistogram`] bucket
+const BUCKET_HISTOGRAM_FIELDS: [&str; BUCKET_HISTOGRAM_BOUNDS.len() + 1] = [
+    "le_1000",
+    "le_2000",
+    "le_5000",
+    "le_10000",
+    "le_20000",
+    "le_50000",
+    "le_100000",
+    "le_200000",
+    "le_500000",
+    "le_1000000",
+    "gt_1000000",
+];
+
+/// Distribution of recorded values, such as the compute units consumed by transactions or the
+/// time it took to JIT compile programs, in fixed buckets.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BucketHistogram {
+    buckets: [u64; BUCKET_HISTOGRAM_BOUNDS.len() + 1],
+    total: u64,
+}
+
+impl BucketHistogram {
+    pub fn record(&mut self, value: u64) {
+        let bucket = BUCKET_HISTOGRAM_BOUNDS
+            .iter()
+            .position(|bound| value <= *bound)
+            .unwrap_or(BUCKET_HISTOGRAM_BOUNDS.len());
+        if let Some(count) = self.buckets.get_mut(bucket) {
+            saturating_add_assign!(*count, 1);
+        }
+        saturating_add_assign!(self.total, value);
+    }
+
+    pub fn accumulate(&mut self, other: &BucketHistogram) {
+        for (bucket, other_bucket) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            saturating_add_assign!(*bucket, *other_bucket);
+        }
+        saturating_add_assign!(self.total, other.total);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.buckets
+            .iter()
+            .fold(0u64, |count, bucket| count.saturating_add(*bucket))
+    }
+
+    pub fn report(&self, name: &'static str, slot: Slot) {
+        let count = self.count();
+        if count == 0 {
+            return;
+        }
+        let mut point = DataPoint::new(name);
+        point
+            .add_field_i64("slot", slot as i64)
+            .add_field_i64("count", count as i64)
+            .add_field_i64("total", self.total as i64);
+        for (field, bucket) in BUCKET_HISTOGRAM_FIELDS.iter().zip(self.buckets.iter()) {
+            point.add_field_i64(*field, *bucket as i64);
+        }
+        solana_metrics::submit(point, log::Level::Info);
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct ExecuteTimings {
     pub metrics: Metrics,
     pub details: ExecuteDetailsTimings,
     pub execute_accessories: ExecuteAccessoryTimings,
+    /// Only populated for sampled slots, see `execute_batch()` in `solana_ledger`.
+    pub transaction_compute_units: BucketHistogram,
 }
 
 impl ExecuteTimings {
@@ -325,6 +399,8 @@ impl ExecuteTimings {
         self.details.accumulate(&other.details);
         self.execute_accessories
             .accumulate(&other.execute_accessories);
+        self.transaction_compute_units
+            .accumulate(&other.transaction_compute_units);
     }
 
     pub fn saturating_add_in_place(&mut self, timing_type: ExecuteTimingType, value_to_add: u64) {
@@ -472,6 +548,24 @@ impl ExecuteDetailsTimings {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_bucket_histogram() {
+        let mut histogram = BucketHistogram::default();
+        histogram.record(0);
+        histogram.record(1_000);
+        histogram.record(1_001);
+        histogram.record(1_400_000);
+        assert_eq!(histogram.buckets, [2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(histogram.total, 1_402_001);
+
+        let mut other = BucketHistogram::default();
+        other.record(150_000);
+        histogram.accumulate(&other);
+        assert_eq!(histogram.buckets, [2, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1]);
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.total, 1_552_001);
+    }
+
     fn construct_execute_timings_with_program(
         program_id: &Pubkey,
         us: u64,
@@ -30,9 +30,11 @@ use {
         get_tmp_ledger_path,
         leader_schedule_cache::LeaderScheduleCache,
     },
+    solana_measure::measure_us,
     solana_metrics::inc_new_counter_info,
     solana_perf::packet::PACKET_DATA_SIZE,
     solana_rpc_client_api::{
+        bundles::{RpcBundleSimulationSummary, RpcSimulateBundleResult},
         config::*,
         custom_error::RpcCustomError,
         deprecated_config::*,
@@ -124,6 +126,8 @@ type RpcCustomResult<T> = std::result::Result<T, RpcCustomError>;
 pub const MAX_REQUEST_BODY_SIZE: usize = 50 * (1 << 10); // 50kB
 pub const PERFORMANCE_SAMPLES_LIMIT: usize = 720;
 
+const SIMULATE_BUNDLE_TIMEOUT_ERROR_CODE: i64 = 10_000;
+
 fn new_response<T>(bank: &Bank, value: T) -> RpcResponse<T> {
     RpcResponse {
         context: RpcResponseContext::new(bank.slot()),
@@ -214,9 +218,84 @@ pub struct JsonRpcRequestProcessor {
     max_complete_transaction_status_slot: Arc<AtomicU64>,
     max_complete_rewards_slot: Arc<AtomicU64>,
     prioritization_fee_cache: Arc<PrioritizationFeeCache>,
+    simulate_bundle_stats: Arc<SimulateBundleStats>,
 }
 impl Metadata for JsonRpcRequestProcessor {}
 
+/// Requests to `simulateBundle`, reported separately from other RPC methods because searcher
+/// load on it is much heavier
+#[derive(Default)]
+pub(crate) struct SimulateBundleStats {
+    num_requests: AtomicU64,
+    num_succeeded: AtomicU64,
+    /// Bundles that were simulated, but a transaction in them failed
+    num_transaction_failed: AtomicU64,
+    num_invalid_params: AtomicU64,
+    num_timed_out: AtomicU64,
+    num_other_errors: AtomicU64,
+    total_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl SimulateBundleStats {
+    fn record(&self, result: &Result<RpcResponse<RpcSimulateBundleResult>>, simulate_us: u64) {
+        self.num_requests.fetch_add(1, Ordering::Relaxed);
+        self.total_us.fetch_add(simulate_us, Ordering::Relaxed);
+        self.max_us.fetch_max(simulate_us, Ordering::Relaxed);
+        let outcome = match result {
+            Ok(response) => match response.value.summary {
+                RpcBundleSimulationSummary::Succeeded => &self.num_succeeded,
+                RpcBundleSimulationSummary::Failed { .. } => &self.num_transaction_failed,
+            },
+            Err(err) => match err.code {
+                ErrorCode::InvalidParams => &self.num_invalid_params,
+                ErrorCode::ServerError(SIMULATE_BUNDLE_TIMEOUT_ERROR_CODE) => &self.num_timed_out,
+                _ => &self.num_other_errors,
+            },
+        };
+        outcome.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Reports and resets the counts recorded since the last report
+    pub(crate) fn report(&self) {
+        datapoint_info!(
+            "rpc-simulate_bundle",
+            (
+                "num_requests",
+                self.num_requests.swap(0, Ordering::Relaxed),
+                i64
+            ),
+            (
+                "num_succeeded",
+                self.num_succeeded.swap(0, Ordering::Relaxed),
+                i64
+            ),
+            (
+                "num_transaction_failed",
+                self.num_transaction_failed.swap(0, Ordering::Relaxed),
+                i64
+            ),
+            (
+                "num_invalid_params",
+                self.num_invalid_params.swap(0, Ordering::Relaxed),
+                i64
+            ),
+            (
+                "num_timed_out",
+                self.num_timed_out.swap(0, Ordering::Relaxed),
+                i64
+            ),
+            (
+                "num_other_errors",
+                self.num_other_errors.swap(0, Ordering::Relaxed),
+                i64
+            ),
+            ("total_us", self.total_us.swap(0, Ordering::Relaxed), i64),
+            ("max_us", self.max_us.swap(0, Ordering::Relaxed), i64),
+        );
+    }
+}
+
 impl JsonRpcRequestProcessor {
     fn get_bank_with_config(&self, config: RpcContextConfig) -> Result<Arc<Bank>> {
         let RpcContextConfig {
@@ -350,6 +429,7 @@ impl JsonRpcRequestProcessor {
                 max_complete_transaction_status_slot,
                 max_complete_rewards_slot,
                 prioritization_fee_cache,
+                simulate_bundle_stats: Arc::default(),
             },
             receiver,
         )
@@ -421,9 +501,14 @@ impl JsonRpcRequestProcessor {
             max_complete_transaction_status_slot: Arc::new(AtomicU64::default()),
             max_complete_rewards_slot: Arc::new(AtomicU64::default()),
             prioritization_fee_cache: Arc::new(PrioritizationFeeCache::default()),
+            simulate_bundle_stats: Arc::default(),
         }
     }
 
+    pub(crate) fn simulate_bundle_stats(&self) -> Arc<SimulateBundleStats> {
+        self.simulate_bundle_stats.clone()
+    }
+
     pub fn get_account_info(
         &self,
         pubkey: &Pubkey,
@@ -4100,144 +4185,19 @@ pub mod rpc_full {
             ))
         }
 
-        // TODO (LB): probably want to add a max transaction size and max account return size and max
-        // allowable simulation time
         fn simulate_bundle(
             &self,
             meta: Self::Metadata,
             rpc_bundle_request: RpcBundleRequest,
             config: Option<RpcSimulateBundleConfig>,
         ) -> Result<RpcResponse<RpcSimulateBundleResult>> {
-            const MAX_BUNDLE_SIMULATION_TIME: Duration = Duration::from_millis(500);
-
             debug!("simulate_bundle rpc request received");
 
-            let config = config.unwrap_or_else(|| RpcSimulateBundleConfig {
-                pre_execution_accounts_configs: vec![
-                    None;
-                    rpc_bundle_request.encoded_transactions.len()
-                ],
-                post_execution_accounts_configs: vec![
-                    None;
-                    rpc_bundle_request.encoded_transactions.len()
-                ],
-                ..RpcSimulateBundleConfig::default()
-            });
-
-            // Run some request validations
-            if !(config.pre_execution_accounts_configs.len()
-                == rpc_bundle_request.encoded_transactions.len()
-                && config.post_execution_accounts_configs.len()
-                    == rpc_bundle_request.encoded_transactions.len())
-            {
-                return Err(Error::invalid_params(
-                    "pre/post_execution_accounts_configs must be equal in length to the number of transactions",
-                ));
-            }
-
-            let bank = match config.simulation_bank.unwrap_or_default() {
-                SimulationSlotConfig::Commitment(commitment) => Ok(meta.bank(Some(commitment))),
-                SimulationSlotConfig::Slot(slot) => meta.bank_from_slot(slot).ok_or_else(|| {
-                    Error::invalid_params(format!("bank not found for the provided slot: {}", slot))
-                }),
-                SimulationSlotConfig::Tip => Ok(meta.bank_forks.read().unwrap().working_bank()),
-            }?;
-
-            let tx_encoding = config
-                .transaction_encoding
-                .unwrap_or(UiTransactionEncoding::Base64);
-            let binary_encoding = tx_encoding.into_binary_encoding().ok_or_else(|| {
-                Error::invalid_params(format!(
-                    "Unsupported encoding: {}. Supported encodings are: base58 & base64",
-                    tx_encoding
-                ))
-            })?;
-            let mut decoded_transactions = rpc_bundle_request
-                .encoded_transactions
-                .into_iter()
-                .map(|encoded_tx| {
-                    decode_and_deserialize::<VersionedTransaction>(encoded_tx, binary_encoding)
-                        .map(|de| de.1)
-                })
-                .collect::<Result<Vec<VersionedTransaction>>>()?;
-
-            if config.replace_recent_blockhash {
-                if !config.skip_sig_verify {
-                    return Err(Error::invalid_params(
-                        "sigVerify may not be used with replaceRecentBlockhash",
-                    ));
-                }
-                decoded_transactions.iter_mut().for_each(|tx| {
-                    tx.message.set_recent_blockhash(bank.last_blockhash());
-                });
-            }
-
-            let bundle_id = derive_bundle_id(&decoded_transactions);
-            let sanitized_bundle = SanitizedBundle {
-                transactions: decoded_transactions
-                    .into_iter()
-                    .map(|tx| sanitize_transaction(tx, bank.as_ref()))
-                    .collect::<Result<Vec<SanitizedTransaction>>>()?,
-                bundle_id,
-            };
-
-            if !config.skip_sig_verify {
-                for tx in &sanitized_bundle.transactions {
-                    verify_transaction(tx, &bank.feature_set)?;
-                }
-            }
-
-            let pre_execution_accounts =
-                account_configs_to_accounts(&config.pre_execution_accounts_configs)?;
-            let post_execution_accounts =
-                account_configs_to_accounts(&config.post_execution_accounts_configs)?;
-
-            let bundle_execution_result = load_and_execute_bundle(
-                &bank,
-                &sanitized_bundle,
-                MAX_PROCESSING_AGE,
-                &MAX_BUNDLE_SIMULATION_TIME,
-                true,
-                true,
-                true,
-                true,
-                &None,
-                true,
-                None,
-                &pre_execution_accounts,
-                &post_execution_accounts,
-            );
-
-            // only return error if irrecoverable (timeout or tx malformed)
-            // bundle execution failures w/ context are returned to client
-            match bundle_execution_result.result() {
-                Ok(()) | Err(LoadAndExecuteBundleError::TransactionError { .. }) => {}
-                Err(LoadAndExecuteBundleError::ProcessingTimeExceeded(elapsed)) => {
-                    let mut error = Error::new(ErrorCode::ServerError(10_000));
-                    error.message = format!(
-                        "simulation time exceeded max allowed time: {:?}ms",
-                        elapsed.as_millis()
-                    );
-                    return Err(error);
-                }
-                Err(LoadAndExecuteBundleError::InvalidPreOrPostAccounts) => {
-                    return Err(Error::invalid_params("invalid pre or post account data"));
-                }
-                Err(LoadAndExecuteBundleError::LockError {
-                    signature,
-                    transaction_error,
-                }) => {
-                    return Err(Error::invalid_params(format!(
-                        "error locking transaction with signature: {}, error: {:?}",
-                        signature, transaction_error
-                    )));
-                }
-            }
-
-            let rpc_bundle_result =
-                rpc_bundle_result_from_bank_result(bundle_execution_result, config)?;
-
-            Ok(new_response(&bank, rpc_bundle_result))
+            let (result, simulate_bundle_us) =
+                measure_us!(simulate_bundle_inner(&meta, rpc_bundle_request, config));
+            meta.simulate_bundle_stats
+                .record(&result, simulate_bundle_us);
+            result
         }
 
         fn minimum_ledger_slot(&self, meta: Self::Metadata) -> Result<Slot> {
@@ -4451,6 +4411,144 @@ pub mod rpc_full {
             meta.get_recent_prioritization_fees(pubkeys)
         }
     }
+
+    // TODO (LB): probably want to add a max transaction size and max account return size and max
+    // allowable simulation time
+    fn simulate_bundle_inner(
+        meta: &JsonRpcRequestProcessor,
+        rpc_bundle_request: RpcBundleRequest,
+        config: Option<RpcSimulateBundleConfig>,
+    ) -> Result<RpcResponse<RpcSimulateBundleResult>> {
+        const MAX_BUNDLE_SIMULATION_TIME: Duration = Duration::from_millis(500);
+
+        let config = config.unwrap_or_else(|| RpcSimulateBundleConfig {
+            pre_execution_accounts_configs: vec![
+                None;
+                rpc_bundle_request.encoded_transactions.len()
+            ],
+            post_execution_accounts_configs: vec![
+                None;
+                rpc_bundle_request.encoded_transactions.len()
+            ],
+            ..RpcSimulateBundleConfig::default()
+        });
+
+        // Run some request validations
+        if !(config.pre_execution_accounts_configs.len()
+            == rpc_bundle_request.encoded_transactions.len()
+            && config.post_execution_accounts_configs.len()
+                == rpc_bundle_request.encoded_transactions.len())
+        {
+            return Err(Error::invalid_params(
+                "pre/post_execution_accounts_configs must be equal in length to the number of transactions",
+            ));
+        }
+
+        let bank = match config.simulation_bank.unwrap_or_default() {
+            SimulationSlotConfig::Commitment(commitment) => Ok(meta.bank(Some(commitment))),
+            SimulationSlotConfig::Slot(slot) => meta.bank_from_slot(slot).ok_or_else(|| {
+                Error::invalid_params(format!("bank not found for the provided slot: {}", slot))
+            }),
+            SimulationSlotConfig::Tip => Ok(meta.bank_forks.read().unwrap().working_bank()),
+        }?;
+
+        let tx_encoding = config
+            .transaction_encoding
+            .unwrap_or(UiTransactionEncoding::Base64);
+        let binary_encoding = tx_encoding.into_binary_encoding().ok_or_else(|| {
+            Error::invalid_params(format!(
+                "Unsupported encoding: {}. Supported encodings are: base58 & base64",
+                tx_encoding
+            ))
+        })?;
+        let mut decoded_transactions = rpc_bundle_request
+            .encoded_transactions
+            .into_iter()
+            .map(|encoded_tx| {
+                decode_and_deserialize::<VersionedTransaction>(encoded_tx, binary_encoding)
+                    .map(|de| de.1)
+            })
+            .collect::<Result<Vec<VersionedTransaction>>>()?;
+
+        if config.replace_recent_blockhash {
+            if !config.skip_sig_verify {
+                return Err(Error::invalid_params(
+                    "sigVerify may not be used with replaceRecentBlockhash",
+                ));
+            }
+            decoded_transactions.iter_mut().for_each(|tx| {
+                tx.message.set_recent_blockhash(bank.last_blockhash());
+            });
+        }
+
+        let bundle_id = derive_bundle_id(&decoded_transactions);
+        let sanitized_bundle = SanitizedBundle {
+            transactions: decoded_transactions
+                .into_iter()
+                .map(|tx| sanitize_transaction(tx, bank.as_ref()))
+                .collect::<Result<Vec<SanitizedTransaction>>>()?,
+            bundle_id,
+        };
+
+        if !config.skip_sig_verify {
+            for tx in &sanitized_bundle.transactions {
+                verify_transaction(tx, &bank.feature_set)?;
+            }
+        }
+
+        let pre_execution_accounts =
+            account_configs_to_accounts(&config.pre_execution_accounts_configs)?;
+        let post_execution_accounts =
+            account_configs_to_accounts(&config.post_execution_accounts_configs)?;
+
+        let bundle_execution_result = load_and_execute_bundle(
+            &bank,
+            &sanitized_bundle,
+            MAX_PROCESSING_AGE,
+            &MAX_BUNDLE_SIMULATION_TIME,
+            true,
+            true,
+            true,
+            true,
+            &None,
+            true,
+            None,
+            &pre_execution_accounts,
+            &post_execution_accounts,
+        );
+
+        // only return error if irrecoverable (timeout or tx malformed)
+        // bundle execution failures w/ context are returned to client
+        match bundle_execution_result.result() {
+            Ok(()) | Err(LoadAndExecuteBundleError::TransactionError { .. }) => {}
+            Err(LoadAndExecuteBundleError::ProcessingTimeExceeded(elapsed)) => {
+                let mut error =
+                    Error::new(ErrorCode::ServerError(SIMULATE_BUNDLE_TIMEOUT_ERROR_CODE));
+                error.message = format!(
+                    "simulation time exceeded max allowed time: {:?}ms",
+                    elapsed.as_millis()
+                );
+                return Err(error);
+            }
+            Err(LoadAndExecuteBundleError::InvalidPreOrPostAccounts) => {
+                return Err(Error::invalid_params("invalid pre or post account data"));
+            }
+            Err(LoadAndExecuteBundleError::LockError {
+                signature,
+                transaction_error,
+            }) => {
+                return Err(Error::invalid_params(format!(
+                    "error locking transaction with signature: {}, error: {:?}",
+                    signature, transaction_error
+                )));
+            }
+        }
+
+        let rpc_bundle_result =
+            rpc_bundle_result_from_bank_result(bundle_execution_result, config)?;
+
+        Ok(new_response(&bank, rpc_bundle_result))
+    }
 }
 
 fn rpc_perf_sample_from_perf_sample(slot: u64, sample: PerfSample) -> RpcPerfSample {
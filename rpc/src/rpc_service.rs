@@ -49,6 +49,7 @@ use {
             Arc, RwLock,
         },
         thread::{self, Builder, JoinHandle},
+        time::Duration,
     },
     tokio_util::codec::{BytesCodec, FramedRead},
 };
@@ -56,6 +57,7 @@ use {
 const FULL_SNAPSHOT_REQUEST_PATH: &str = "/snapshot.tar.bz2";
 const INCREMENTAL_SNAPSHOT_REQUEST_PATH: &str = "/incremental-snapshot.tar.bz2";
 const LARGEST_ACCOUNTS_CACHE_DURATION: u64 = 60 * 60 * 2;
+const SIMULATE_BUNDLE_STATS_REPORT_INTERVAL: Duration = Duration::from_secs(10);
 
 pub struct JsonRpcService {
     thread_hdl: JoinHandle<()>,
@@ -474,6 +476,16 @@ impl JsonRpcService {
             prioritization_fee_cache,
         );
 
+        // report on a timer rather than from the handler so that idle periods are reported too
+        let simulate_bundle_stats = request_processor.simulate_bundle_stats();
+        runtime.spawn(async move {
+            let mut interval = tokio::time::interval(SIMULATE_BUNDLE_STATS_REPORT_INTERVAL);
+            loop {
+                interval.tick().await;
+                simulate_bundle_stats.report();
+            }
+        });
+
         let leader_info =
             poh_recorder.map(|recorder| ClusterTpuInfo::new(cluster_info.clone(), recorder));
         let _send_transaction_service = Arc::new(SendTransactionService::new_with_config(
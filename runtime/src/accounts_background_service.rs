@@ -23,7 +23,7 @@ use {
     },
     solana_measure::{measure::Measure, measure_us},
     solana_sdk::clock::{BankId, Slot},
-    stats::StatsManager,
+    stats::{StatsManager, TaskTimings},
     std::{
         boxed::Box,
         fmt::{Debug, Formatter},
@@ -623,13 +623,13 @@ impl AccountsBackgroundService {
                     let bank = bank_forks.read().unwrap().root_bank();
 
                     // Purge accounts of any dead slots
-                    request_handlers
+                    let (_, remove_dead_slots_us) = measure_us!(request_handlers
                         .pruned_banks_request_handler
                         .remove_dead_slots(
                             &bank,
                             &mut removed_slots_count,
                             &mut total_remove_slots_time,
-                        );
+                        ));
 
                     Self::expire_old_recycle_stores(&bank, &mut last_expiration_check_time);
 
@@ -661,7 +661,7 @@ impl AccountsBackgroundService {
                     // snapshot requests.  This is because startup verification and snapshot
                     // request handling can both kick off accounts hash calculations in background
                     // threads, and these must not happen concurrently.
-                    let snapshot_handle_result = bank
+                    let (snapshot_handle_result, snapshot_requests_us) = measure_us!(bank
                         .is_startup_verification_complete()
                         .then(|| {
                             request_handlers.handle_snapshot_requests(
@@ -671,7 +671,7 @@ impl AccountsBackgroundService {
                                 &exit,
                             )
                         })
-                        .flatten();
+                        .flatten());
                     if snapshot_handle_result.is_some() {
                         last_snapshot_end_time = Some(Instant::now());
                     }
@@ -680,7 +680,13 @@ impl AccountsBackgroundService {
                     // cache up to bank.slot(), so should be safe as long
                     // as any later snapshots that are taken are of
                     // slots >= bank.slot()
-                    bank.flush_accounts_cache_if_needed();
+                    let (_, flush_us) = measure_us!(bank.flush_accounts_cache_if_needed());
+                    let mut task_timings = TaskTimings {
+                        remove_dead_slots_us,
+                        snapshot_requests_us,
+                        flush_us,
+                        ..TaskTimings::default()
+                    };
 
                     if let Some(snapshot_handle_result) = snapshot_handle_result {
                         // Safe, see proof above
@@ -704,12 +710,18 @@ impl AccountsBackgroundService {
                             // cache up to bank.slot(), so should be safe as long
                             // as any later snapshots that are taken are of
                             // slots >= bank.slot()
-                            bank.force_flush_accounts_cache();
-                            bank.clean_accounts(last_full_snapshot_slot);
+                            let (_, force_flush_us) =
+                                measure_us!(bank.force_flush_accounts_cache());
+                            task_timings.flush_us += force_flush_us;
+                            let (_, clean_us) =
+                                measure_us!(bank.clean_accounts(last_full_snapshot_slot));
+                            task_timings.clean_us = clean_us;
                             last_cleaned_block_height = bank.block_height();
                             // See justification below for why we skip 'shrink' here.
                             if bank.is_startup_verification_complete() {
-                                bank.shrink_ancient_slots();
+                                let (_, shrink_ancient_us) =
+                                    measure_us!(bank.shrink_ancient_slots());
+                                task_timings.shrink_ancient_us = shrink_ancient_us;
                             }
                         }
                         // Do not 'shrink' until *after* the startup verification is complete.
@@ -720,10 +732,16 @@ impl AccountsBackgroundService {
                         // progress, or (2) could get snapshot storages that were newer than what
                         // was in the snapshot itself.
                         if bank.is_startup_verification_complete() {
-                            bank.shrink_candidate_slots();
+                            let (_, shrink_us) = measure_us!(bank.shrink_candidate_slots());
+                            task_timings.shrink_us = shrink_us;
                         }
                     }
-                    stats.record_and_maybe_submit(start_time.elapsed());
+                    stats.record_and_maybe_submit(
+                        start_time.elapsed(),
+                        &task_timings,
+                        &bank,
+                        last_cleaned_block_height,
+                    );
                     sleep(Duration::from_millis(INTERVAL_MS));
                 }
                 info!("AccountsBackgroundService has stopped");
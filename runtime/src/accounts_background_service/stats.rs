@@ -1,6 +1,7 @@
 //! Stats for Accounts Background Services
 
 use {
+    crate::bank::Bank,
     solana_metrics::datapoint_info,
     std::time::{Duration, Instant},
 };
@@ -28,13 +29,21 @@ impl StatsManager {
 
     /// Record stats from this iteration, and maybe submit the datapoints based on how long it has
     /// been since the previous submission.
-    pub(super) fn record_and_maybe_submit(&mut self, runtime: Duration) {
-        self.stats.record(runtime);
-        self.maybe_submit();
+    ///
+    /// The backlogs are sampled from `root_bank` at submission.
+    pub(super) fn record_and_maybe_submit(
+        &mut self,
+        runtime: Duration,
+        task_timings: &TaskTimings,
+        root_bank: &Bank,
+        last_cleaned_block_height: u64,
+    ) {
+        self.stats.record(runtime, task_timings);
+        self.maybe_submit(root_bank, last_cleaned_block_height);
     }
 
     /// Maybe submit the datapoints based on how long it has been since the previous submission.
-    fn maybe_submit(&mut self) {
+    fn maybe_submit(&mut self, root_bank: &Bank, last_cleaned_block_height: u64) {
         let duration_since_previous_submit = Instant::now() - self.previous_submit;
         if duration_since_previous_submit < SUBMIT_INTERVAL {
             return;
@@ -60,6 +69,61 @@ impl StatsManager {
             ),
             ("min_runtime_us", self.stats.min_runtime.as_micros(), i64),
             ("max_runtime_us", self.stats.max_runtime.as_micros(), i64),
+            (
+                "remove_dead_slots_us",
+                self.stats.task_timings.remove_dead_slots_us,
+                i64
+            ),
+            (
+                "snapshot_requests_us",
+                self.stats.task_timings.snapshot_requests_us,
+                i64
+            ),
+            ("flush_us", self.stats.task_timings.flush_us, i64),
+            ("clean_us", self.stats.task_timings.clean_us, i64),
+            (
+                "shrink_ancient_us",
+                self.stats.task_timings.shrink_ancient_us,
+                i64
+            ),
+            ("shrink_us", self.stats.task_timings.shrink_us, i64),
+        );
+
+        let accounts_db = &root_bank.rc.accounts.accounts_db;
+        let root = root_bank.slot();
+        let unflushed_roots = accounts_db
+            .accounts_cache
+            .cached_frozen_slots()
+            .into_iter()
+            .filter(|slot| *slot <= root)
+            .count();
+        datapoint_info!(
+            "accounts_background_service-backlog",
+            ("root", root, i64),
+            (
+                "accounts_cache_slots",
+                accounts_db.accounts_cache.num_slots(),
+                i64
+            ),
+            ("unflushed_roots", unflushed_roots, i64),
+            (
+                "uncleaned_roots",
+                accounts_db.accounts_index.num_uncleaned_roots(),
+                i64
+            ),
+            (
+                "shrink_candidates",
+                accounts_db.shrink_candidate_slots.lock().unwrap().len(),
+                i64
+            ),
+            ("last_cleaned_block_height", last_cleaned_block_height, i64),
+            (
+                "blocks_since_last_clean",
+                root_bank
+                    .block_height()
+                    .saturating_sub(last_cleaned_block_height),
+                i64
+            ),
         );
 
         // reset the stats back to default
@@ -67,6 +131,37 @@ impl StatsManager {
     }
 }
 
+/// Time spent in each task of one iteration of the ABS main loop
+///
+/// Tasks done while handling a snapshot request are included in `snapshot_requests_us`, and
+/// have their own datapoint.
+#[derive(Debug, Default)]
+pub(super) struct TaskTimings {
+    pub(super) remove_dead_slots_us: u64,
+    pub(super) snapshot_requests_us: u64,
+    pub(super) flush_us: u64,
+    pub(super) clean_us: u64,
+    pub(super) shrink_ancient_us: u64,
+    pub(super) shrink_us: u64,
+}
+
+impl TaskTimings {
+    fn accumulate(&mut self, other: &Self) {
+        self.remove_dead_slots_us = self
+            .remove_dead_slots_us
+            .saturating_add(other.remove_dead_slots_us);
+        self.snapshot_requests_us = self
+            .snapshot_requests_us
+            .saturating_add(other.snapshot_requests_us);
+        self.flush_us = self.flush_us.saturating_add(other.flush_us);
+        self.clean_us = self.clean_us.saturating_add(other.clean_us);
+        self.shrink_ancient_us = self
+            .shrink_ancient_us
+            .saturating_add(other.shrink_ancient_us);
+        self.shrink_us = self.shrink_us.saturating_add(other.shrink_us);
+    }
+}
+
 /// Stats for Accounts Background Services
 ///
 /// Intended to record stats for each iteration of the ABS main loop.
@@ -80,15 +175,18 @@ struct Stats {
     min_runtime: Duration,
     /// Maximum runtime seen for one iteration
     max_runtime: Duration,
+    /// Total time spent in each task over all iterations
+    task_timings: TaskTimings,
 }
 
 impl Stats {
     /// Record stats from this iteration
-    fn record(&mut self, runtime: Duration) {
+    fn record(&mut self, runtime: Duration, task_timings: &TaskTimings) {
         self.num_iterations += 1;
         self.cumulative_runtime += runtime;
         self.min_runtime = self.min_runtime.min(runtime);
         self.max_runtime = self.max_runtime.max(runtime);
+        self.task_timings.accumulate(task_timings);
     }
 
     /// Calculate the mean runtime of all iterations
@@ -109,6 +207,7 @@ impl Default for Stats {
             cumulative_runtime: Duration::ZERO,
             min_runtime: Duration::MAX,
             max_runtime: Duration::ZERO,
+            task_timings: TaskTimings::default(),
         }
     }
 }
@@ -123,7 +222,7 @@ mod tests {
 
         // record first stat, will be both min and max
         let runtime1 = Duration::from_secs(44);
-        stats.record(runtime1);
+        stats.record(runtime1, &TaskTimings::default());
         assert_eq!(stats.num_iterations, 1);
         assert_eq!(stats.cumulative_runtime, runtime1);
         assert_eq!(stats.min_runtime, runtime1);
@@ -131,7 +230,7 @@ mod tests {
 
         // record a new max
         let runtime2 = Duration::from_secs(99);
-        stats.record(runtime2);
+        stats.record(runtime2, &TaskTimings::default());
         assert_eq!(stats.num_iterations, 2);
         assert_eq!(stats.cumulative_runtime, runtime1 + runtime2);
         assert_eq!(stats.min_runtime, runtime1);
@@ -139,20 +238,45 @@ mod tests {
 
         // record a new min
         let runtime3 = Duration::from_secs(11);
-        stats.record(runtime3);
+        stats.record(runtime3, &TaskTimings::default());
         assert_eq!(stats.num_iterations, 3);
         assert_eq!(stats.cumulative_runtime, runtime1 + runtime2 + runtime3);
         assert_eq!(stats.min_runtime, runtime3);
         assert_eq!(stats.max_runtime, runtime2);
     }
 
+    #[test]
+    fn test_stats_record_task_timings() {
+        let mut stats = Stats::default();
+        stats.record(
+            Duration::from_secs(1),
+            &TaskTimings {
+                flush_us: 10,
+                clean_us: 20,
+                ..TaskTimings::default()
+            },
+        );
+        stats.record(
+            Duration::from_secs(1),
+            &TaskTimings {
+                flush_us: 5,
+                shrink_us: 7,
+                ..TaskTimings::default()
+            },
+        );
+        assert_eq!(stats.task_timings.flush_us, 15);
+        assert_eq!(stats.task_timings.clean_us, 20);
+        assert_eq!(stats.task_timings.shrink_us, 7);
+        assert_eq!(stats.task_timings.snapshot_requests_us, 0);
+    }
+
     #[test]
     fn test_stats_mean_runtime() {
         let mut stats = Stats::default();
-        stats.record(Duration::from_secs(1));
-        stats.record(Duration::from_secs(3));
-        stats.record(Duration::from_secs(5));
-        stats.record(Duration::from_secs(7));
+        stats.record(Duration::from_secs(1), &TaskTimings::default());
+        stats.record(Duration::from_secs(3), &TaskTimings::default());
+        stats.record(Duration::from_secs(5), &TaskTimings::default());
+        stats.record(Duration::from_secs(7), &TaskTimings::default());
         assert_eq!(stats.mean_runtime().as_secs(), (1 + 3 + 5 + 7) / 4);
     }
 
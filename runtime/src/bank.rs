@@ -177,7 +177,7 @@ use {
         stake::state::Delegation,
         system_transaction,
         sysvar::{self, last_restart_slot::LastRestartSlot, Sysvar, SysvarId},
-        timing::years_as_slots,
+        timing::{timestamp, years_as_slots},
         transaction::{
             self, MessageHash, Result, SanitizedTransaction, Transaction, TransactionError,
             TransactionVerificationMode, VersionedTransaction, MAX_TX_ACCOUNT_LOCKS,
@@ -1519,6 +1519,9 @@ impl Bank {
     ) {
         let epoch = self.epoch();
         let slot = self.slot();
+        // Counts every bank that crosses an epoch boundary, including those on
+        // forks that are later abandoned
+        inc_new_counter_info!("bank-new_from_parent-epoch_rollover", 1, 1, 1);
         let (thread_pool, thread_pool_time) = measure!(
             ThreadPoolBuilder::new().build().unwrap(),
             "thread_pool_creation",
@@ -3534,7 +3537,7 @@ impl Bank {
             .fetch_add(total_rewards_in_lamports, Relaxed);
 
         // decrease distributed capital from epoch rewards sysvar
-        self.update_epoch_rewards_sysvar(total_rewards_in_lamports);
+        let epoch_rewards = self.update_epoch_rewards_sysvar(total_rewards_in_lamports);
 
         // update reward history for this partitioned distribution
         self.update_reward_history_in_partition(this_partition_stake_rewards);
@@ -3542,11 +3545,14 @@ impl Bank {
         let metrics = RewardsStoreMetrics {
             pre_capitalization,
             post_capitalization: self.capitalization(),
-            total_stake_accounts_count: all_stake_rewards.len(),
+            total_stake_accounts_count: all_stake_rewards.iter().map(Vec::len).sum(),
+            num_partitions: all_stake_rewards.len(),
             partition_index,
             store_stake_accounts_us,
             store_stake_accounts_count: this_partition_stake_rewards.len(),
             distributed_rewards: total_rewards_in_lamports,
+            epoch_total_rewards: epoch_rewards.total_rewards,
+            epoch_distributed_rewards: epoch_rewards.distributed_rewards,
         };
 
         report_partitioned_reward_metrics(self, metrics);
@@ -3603,8 +3609,8 @@ impl Bank {
         self.log_epoch_rewards_sysvar("create");
     }
 
-    /// Update EpochRewards sysvar with distributed rewards
-    fn update_epoch_rewards_sysvar(&self, distributed: u64) {
+    /// Update EpochRewards sysvar with distributed rewards, returning the updated sysvar
+    fn update_epoch_rewards_sysvar(&self, distributed: u64) -> sysvar::epoch_rewards::EpochRewards {
         assert!(self.is_partitioned_rewards_code_enabled());
 
         let mut epoch_rewards: sysvar::epoch_rewards::EpochRewards =
@@ -3622,6 +3628,7 @@ impl Bank {
         });
 
         self.log_epoch_rewards_sysvar("update");
+        epoch_rewards
     }
 
     /// Create the persistent PDA containing the epoch-rewards data
@@ -4029,6 +4036,16 @@ impl Bank {
         self.fee_rate_governor.lamports_per_signature
     }
 
+    /// Number of blockhashes in the queue, and how long ago (in milliseconds) the oldest one that
+    /// transactions can still reference was registered
+    pub fn blockhash_queue_stats(&self) -> (usize, Option<u64>) {
+        let blockhash_queue = self.blockhash_queue.read().unwrap();
+        let oldest_valid_blockhash_age_ms = blockhash_queue
+            .get_oldest_hash_valid_for_age(MAX_PROCESSING_AGE)
+            .map(|(_, registered_at)| timestamp().saturating_sub(registered_at));
+        (blockhash_queue.len(), oldest_valid_blockhash_age_ms)
+    }
+
     pub fn get_lamports_per_signature_for_blockhash(&self, hash: &Hash) -> Option<u64> {
         let blockhash_queue = self.blockhash_queue.read().unwrap();
         blockhash_queue.get_lamports_per_signature(hash)
@@ -4884,6 +4901,14 @@ impl Bank {
 
         let mut timings = ExecuteDetailsTimings::default();
         load_program_metrics.submit_datapoint(&mut timings);
+        if load_program_metrics.jit_compile_us > 0 {
+            loaded_programs_cache
+                .stats
+                .jit_compile_us
+                .lock()
+                .unwrap()
+                .record(load_program_metrics.jit_compile_us);
+        }
         if let Some(recompile) = recompile {
             loaded_program.effective_slot = loaded_program.effective_slot.max(
                 self.epoch_schedule()
@@ -8034,6 +8059,20 @@ impl Bank {
                         self.store_account(feature_id, &account);
                     }
                     info!("Feature {} activated at slot {}", feature_id, self.slot());
+                    datapoint_info!(
+                        "bank-feature_activated",
+                        ("feature_id", feature_id.to_string(), String),
+                        (
+                            "description",
+                            feature_set::FEATURE_NAMES
+                                .get(feature_id)
+                                .copied()
+                                .unwrap_or_default(),
+                            String
+                        ),
+                        ("slot", self.slot(), i64),
+                        ("epoch", self.epoch(), i64),
+                    );
                 }
             }
         }
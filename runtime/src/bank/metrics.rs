@@ -162,7 +162,12 @@ pub(crate) struct RewardsStoreMetrics {
     pub(crate) store_stake_accounts_us: u64,
     pub(crate) store_stake_accounts_count: usize,
     pub(crate) total_stake_accounts_count: usize,
+    pub(crate) num_partitions: usize,
     pub(crate) distributed_rewards: u64,
+    /// Total rewards to distribute this epoch, from the EpochRewards sysvar
+    pub(crate) epoch_total_rewards: u64,
+    /// Rewards distributed so far this epoch, including this partition
+    pub(crate) epoch_distributed_rewards: u64,
     pub(crate) pre_capitalization: u64,
     pub(crate) post_capitalization: u64,
 }
@@ -175,6 +180,12 @@ pub(crate) fn report_partitioned_reward_metrics(bank: &Bank, timings: RewardsSto
         ("block_height", bank.block_height(), i64),
         ("parent_slot", bank.parent_slot(), i64),
         ("partition_index", timings.partition_index, i64),
+        ("num_partitions", timings.num_partitions, i64),
+        (
+            "num_partitions_completed",
+            timings.partition_index.saturating_add(1),
+            i64
+        ),
         (
             "store_stake_accounts_us",
             timings.store_stake_accounts_us,
@@ -191,6 +202,12 @@ pub(crate) fn report_partitioned_reward_metrics(bank: &Bank, timings: RewardsSto
             i64
         ),
         ("distributed_rewards", timings.distributed_rewards, i64),
+        ("epoch_total_rewards", timings.epoch_total_rewards, i64),
+        (
+            "epoch_distributed_rewards",
+            timings.epoch_distributed_rewards,
+            i64
+        ),
         ("pre_capitalization", timings.pre_capitalization, i64),
         ("post_capitalization", timings.post_capitalization, i64),
     );
@@ -485,6 +485,16 @@ impl BankForks {
                 i64
             ),
             ("total_banks", self.banks.len(), i64),
+            (
+                "banks_slot_range",
+                self.highest_slot().saturating_sub(root),
+                i64
+            ),
+            (
+                "unrooted_banks_cached_bytes",
+                self.unrooted_banks_cached_bytes(),
+                i64
+            ),
             (
                 "total_squash_cache_ms",
                 set_root_metrics.timings.total_squash_time.squash_cache_ms,
@@ -559,6 +569,19 @@ impl BankForks {
         removed_banks
     }
 
+    /// Approximate memory retained by banks that are not yet rooted, measured as
+    /// the size of the account writes they hold in the accounts write cache
+    fn unrooted_banks_cached_bytes(&self) -> u64 {
+        let root = self.root();
+        let accounts_cache = &self.root_bank().accounts().accounts_db.accounts_cache;
+        self.banks
+            .keys()
+            .filter(|slot| **slot > root)
+            .filter_map(|slot| accounts_cache.slot_cache(*slot))
+            .map(|slot_cache| slot_cache.total_bytes())
+            .sum()
+    }
+
     pub fn root(&self) -> Slot {
         self.root.load(Ordering::Relaxed)
     }
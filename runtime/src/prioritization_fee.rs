@@ -4,6 +4,9 @@ use {
     std::collections::HashMap,
 };
 
+/// Number of programs reported per block, ordered by total prioritization fees paid.
+const MAX_REPORTED_PROGRAMS: usize = 10;
+
 #[derive(Debug, Default)]
 struct PrioritizationFeeMetrics {
     // Count of writable accounts in slot
@@ -25,6 +28,9 @@ struct PrioritizationFeeMetrics {
     // Total prioritization fees included in this slot.
     total_prioritization_fee: u64,
 
+    // Total prioritization fees, in lamports, paid by transactions in this slot.
+    total_prioritization_fee_lamports: u64,
+
     // The minimum prioritization fee of prioritized transactions in this slot.
     min_prioritization_fee: Option<u64>,
 
@@ -40,6 +46,10 @@ impl PrioritizationFeeMetrics {
         saturating_add_assign!(self.total_prioritization_fee, val);
     }
 
+    fn accumulate_total_prioritization_fee_lamports(&mut self, val: u64) {
+        saturating_add_assign!(self.total_prioritization_fee_lamports, val);
+    }
+
     fn accumulate_total_update_elapsed_us(&mut self, val: u64) {
         saturating_add_assign!(self.total_update_elapsed_us, val);
     }
@@ -99,6 +109,11 @@ impl PrioritizationFeeMetrics {
                 self.total_prioritization_fee as i64,
                 i64
             ),
+            (
+                "total_prioritization_fee_lamports",
+                self.total_prioritization_fee_lamports as i64,
+                i64
+            ),
             (
                 "min_prioritization_fee",
                 self.min_prioritization_fee.unwrap_or(0) as i64,
@@ -144,6 +159,10 @@ pub struct PrioritizationFee {
     // The minimum prioritization fee of each writable account in transactions in this block.
     min_writable_account_fees: HashMap<Pubkey, u64>,
 
+    // Total prioritization fees, in lamports, paid by transactions in this block, by the
+    // top-level program each transaction invokes.
+    program_fees: HashMap<Pubkey, u64>,
+
     // Default to `false`, set to `true` when a block is completed, therefore the minimum fees recorded
     // are finalized, and can be made available for use (e.g., RPC query)
     is_finalized: bool,
@@ -157,6 +176,7 @@ impl Default for PrioritizationFee {
         PrioritizationFee {
             min_transaction_fee: u64::MAX,
             min_writable_account_fees: HashMap::new(),
+            program_fees: HashMap::new(),
             is_finalized: false,
             metrics: PrioritizationFeeMetrics::default(),
         }
@@ -202,6 +222,27 @@ impl PrioritizationFee {
         Ok(())
     }
 
+    /// Accumulate the prioritization fee, in lamports, paid by a transaction invoking `program_id`.
+    pub fn update_program_fee(&mut self, program_id: Pubkey, prioritization_fee: u64) {
+        if !self.is_finalized && prioritization_fee > 0 {
+            let program_fees = self.program_fees.entry(program_id).or_default();
+            saturating_add_assign!(*program_fees, prioritization_fee);
+            self.metrics
+                .accumulate_total_prioritization_fee_lamports(prioritization_fee);
+        }
+    }
+
+    /// Only the programs that paid the most fees are reported, the rest are removed to reduce
+    /// memory footprint when mark_block_completed() is called.
+    fn prune_unreported_programs(&mut self) {
+        if self.program_fees.len() > MAX_REPORTED_PROGRAMS {
+            let mut program_fees: Vec<_> = self.program_fees.drain().collect();
+            program_fees.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+            program_fees.truncate(MAX_REPORTED_PROGRAMS);
+            self.program_fees = program_fees.into_iter().collect();
+        }
+    }
+
     /// Accounts that have minimum fees lesser or equal to the minimum fee in the block are redundant, they are
     /// removed to reduce memory footprint when mark_block_completed() is called.
     fn prune_irrelevant_writable_accounts(&mut self) {
@@ -216,6 +257,7 @@ impl PrioritizationFee {
             return Err(PrioritizationFeeError::BlockIsAlreadyFinalized);
         }
         self.prune_irrelevant_writable_accounts();
+        self.prune_unreported_programs();
         self.is_finalized = true;
         Ok(())
     }
@@ -232,6 +274,18 @@ impl PrioritizationFee {
         self.min_writable_account_fees.iter()
     }
 
+    /// Programs ordered by the total prioritization fees paid by transactions invoking them.
+    pub fn get_top_program_fees(&self) -> Vec<(Pubkey, u64)> {
+        let mut program_fees: Vec<_> = self
+            .program_fees
+            .iter()
+            .map(|(program_id, fees)| (*program_id, *fees))
+            .collect();
+        program_fees.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+        program_fees.truncate(MAX_REPORTED_PROGRAMS);
+        program_fees
+    }
+
     pub fn get_writable_accounts_count(&self) -> usize {
         self.min_writable_account_fees.len()
     }
@@ -261,6 +315,23 @@ impl PrioritizationFee {
                 ("min_prioritization_fee", **fee as i64, i64),
             );
         }
+
+        for (rank, (program_id, priority_fees)) in
+            self.get_top_program_fees().into_iter().enumerate()
+        {
+            datapoint_info!(
+                "block_priority_fees_by_program",
+                ("slot", slot as i64, i64),
+                ("rank", rank as i64, i64),
+                ("program_id", program_id.to_string(), String),
+                ("priority_fees", priority_fees as i64, i64),
+                (
+                    "total_priority_fees",
+                    self.metrics.total_prioritization_fee_lamports as i64,
+                    i64
+                ),
+            );
+        }
     }
 }
 
@@ -389,4 +460,33 @@ mod tests {
         assert!(prioritization_fee.mark_block_completed().is_ok());
         assert!(prioritization_fee.mark_block_completed().is_err());
     }
+
+    #[test]
+    fn test_update_program_fee() {
+        let program_a = Pubkey::new_unique();
+        let program_b = Pubkey::new_unique();
+
+        let mut prioritization_fee = PrioritizationFee::default();
+        prioritization_fee.update_program_fee(program_a, 2);
+        prioritization_fee.update_program_fee(program_b, 1);
+        prioritization_fee.update_program_fee(program_a, 3);
+        // no prioritization fee paid, not tracked
+        prioritization_fee.update_program_fee(Pubkey::new_unique(), 0);
+        assert_eq!(
+            prioritization_fee.get_top_program_fees(),
+            vec![(program_a, 5), (program_b, 1)]
+        );
+
+        // only the reported programs are kept once the block is completed
+        for fee in 10..20 {
+            prioritization_fee.update_program_fee(Pubkey::new_unique(), fee);
+        }
+        assert!(prioritization_fee.mark_block_completed().is_ok());
+        assert_eq!(prioritization_fee.program_fees.len(), MAX_REPORTED_PROGRAMS);
+        assert!(!prioritization_fee.program_fees.contains_key(&program_b));
+
+        // updates after the block is completed are ignored
+        prioritization_fee.update_program_fee(program_b, 100);
+        assert!(!prioritization_fee.program_fees.contains_key(&program_b));
+    }
 }
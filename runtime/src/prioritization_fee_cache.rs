@@ -8,8 +8,10 @@ use {
     log::*,
     lru::LruCache,
     solana_measure::measure,
+    solana_program_runtime::prioritization_fee::{PrioritizationFeeDetails, PrioritizationFeeType},
     solana_sdk::{
         clock::{BankId, Slot},
+        compute_budget,
         pubkey::Pubkey,
         transaction::SanitizedTransaction,
     },
@@ -127,6 +129,7 @@ enum CacheServiceUpdate {
         bank_id: BankId,
         transaction_fee: u64,
         writable_accounts: Arc<Vec<Pubkey>>,
+        program_fee: Option<(Pubkey, u64)>,
     },
     BankFinalized {
         slot: Slot,
@@ -148,6 +151,8 @@ pub struct PrioritizationFeeCache {
     service_thread: Option<JoinHandle<()>>,
     sender: Sender<CacheServiceUpdate>,
     metrics: Arc<PrioritizationFeeCacheMetrics>,
+    /// Blocks produced by this leader have their prioritization fees attributed to programs
+    leader_identity: Option<Pubkey>,
 }
 
 impl Default for PrioritizationFeeCache {
@@ -189,9 +194,18 @@ impl PrioritizationFeeCache {
             service_thread,
             sender,
             metrics,
+            leader_identity: None,
         }
     }
 
+    /// Like `default`, but also attributes the prioritization fees of the blocks produced by
+    /// `leader_identity` to the programs that pay them
+    pub fn new_with_leader_identity(leader_identity: Pubkey) -> Self {
+        let mut prioritization_fee_cache = Self::default();
+        prioritization_fee_cache.leader_identity = Some(leader_identity);
+        prioritization_fee_cache
+    }
+
     /// Get prioritization fee entry, create new entry if necessary
     fn get_prioritization_fee(
         cache: Arc<RwLock<LruCache<Slot, Arc<SlotPrioritizationFee>>>>,
@@ -212,6 +226,12 @@ impl PrioritizationFeeCache {
     /// transactions have both valid priority_detail and account_locks will be used to update
     /// fee_cache asynchronously.
     pub fn update<'a>(&self, bank: &Bank, txs: impl Iterator<Item = &'a SanitizedTransaction>) {
+        // Per-program fee attribution is only needed while investigating, so it is enabled
+        // together with debug logging for this module, which can be toggled at runtime with
+        // `solana-validator set-log-filter`.
+        // The cache is also fed by replay, so only our own leader blocks are attributed.
+        let attribute_program_fees = log_enabled!(Level::Debug)
+            && self.leader_identity.as_ref() == Some(bank.collector_id());
         let (_, send_updates_time) = measure!(
             {
                 for sanitized_transaction in txs {
@@ -238,6 +258,20 @@ impl PrioritizationFeeCache {
                         continue;
                     }
 
+                    // attribute the prioritization fee, in lamports, to the first program the
+                    // transaction invokes
+                    let program_fee = attribute_program_fees
+                        .then(|| top_level_program_id(sanitized_transaction))
+                        .flatten()
+                        .map(|program_id| {
+                            let prioritization_fee = PrioritizationFeeDetails::new(
+                                PrioritizationFeeType::ComputeUnitPrice(priority_details.priority),
+                                priority_details.compute_unit_limit,
+                            )
+                            .get_fee();
+                            (*program_id, prioritization_fee)
+                        });
+
                     let writable_accounts = Arc::new(
                         account_locks
                             .unwrap()
@@ -253,6 +287,7 @@ impl PrioritizationFeeCache {
                             bank_id: bank.bank_id(),
                             transaction_fee: priority_details.priority,
                             writable_accounts,
+                            program_fee,
                         })
                         .unwrap_or_else(|err| {
                             warn!(
@@ -290,6 +325,7 @@ impl PrioritizationFeeCache {
         bank_id: &BankId,
         transaction_fee: u64,
         writable_accounts: Arc<Vec<Pubkey>>,
+        program_fee: Option<(Pubkey, u64)>,
         metrics: Arc<PrioritizationFeeCacheMetrics>,
     ) {
         let (slot_prioritization_fee, cache_lock_time) =
@@ -300,6 +336,9 @@ impl PrioritizationFeeCache {
                 let mut block_prioritization_fee = slot_prioritization_fee
                     .entry(*bank_id)
                     .or_insert(PrioritizationFee::default());
+                if let Some((program_id, prioritization_fee)) = program_fee {
+                    block_prioritization_fee.update_program_fee(program_id, prioritization_fee);
+                }
                 block_prioritization_fee.update(transaction_fee, &writable_accounts)
             },
             "entry_update_time"
@@ -368,12 +407,14 @@ impl PrioritizationFeeCache {
                     bank_id,
                     transaction_fee,
                     writable_accounts,
+                    program_fee,
                 } => Self::update_cache(
                     cache.clone(),
                     &slot,
                     &bank_id,
                     transaction_fee,
                     writable_accounts,
+                    program_fee,
                     metrics.clone(),
                 ),
                 CacheServiceUpdate::BankFinalized { slot, bank_id } => {
@@ -431,6 +472,15 @@ impl PrioritizationFeeCache {
     }
 }
 
+/// The first program invoked by the transaction that is not the compute budget program.
+fn top_level_program_id(transaction: &SanitizedTransaction) -> Option<&Pubkey> {
+    transaction
+        .message()
+        .program_instructions_iter()
+        .map(|(program_id, _)| program_id)
+        .find(|program_id| !compute_budget::check_id(program_id))
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -13,11 +13,12 @@ use {
     solana_sdk::{packet::Packet, pubkey::Pubkey, timing::timestamp},
     std::{
         cmp::Reverse,
-        collections::HashMap,
+        collections::{HashMap, HashSet},
+        fs,
         net::{IpAddr, UdpSocket},
         sync::{
-            atomic::{AtomicBool, AtomicUsize, Ordering},
-            Arc,
+            atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+            Arc, Mutex,
         },
         thread::{sleep, Builder, JoinHandle},
         time::{Duration, Instant},
@@ -25,6 +26,9 @@ use {
     thiserror::Error,
 };
 
+/// Kernel tables of UDP sockets, including the number of datagrams each socket dropped
+const PROC_NET_UDP_PATHS: [&str; 2] = ["/proc/net/udp", "/proc/net/udp6"];
+
 // Total stake and nodes => stake map
 #[derive(Default)]
 pub struct StakedNodes {
@@ -56,9 +60,14 @@ pub enum StreamerError {
 pub struct StreamerReceiveStats {
     pub name: &'static str,
     pub packets_count: AtomicUsize,
+    pub bytes_count: AtomicUsize,
     pub packet_batches_count: AtomicUsize,
     pub full_packet_batches_count: AtomicUsize,
     pub max_channel_len: AtomicUsize,
+    /// Ports of the sockets being received from, to look up how many datagrams the kernel
+    /// dropped on them, e.g. because their receive buffer overflowed
+    local_ports: Mutex<HashSet<u16>>,
+    last_socket_drops: AtomicU64,
 }
 
 impl StreamerReceiveStats {
@@ -66,12 +75,38 @@ impl StreamerReceiveStats {
         Self {
             name,
             packets_count: AtomicUsize::default(),
+            bytes_count: AtomicUsize::default(),
             packet_batches_count: AtomicUsize::default(),
             full_packet_batches_count: AtomicUsize::default(),
             max_channel_len: AtomicUsize::default(),
+            local_ports: Mutex::default(),
+            last_socket_drops: AtomicU64::default(),
+        }
+    }
+
+    fn add_socket(&self, socket: &UdpSocket) {
+        if let Ok(local_addr) = socket.local_addr() {
+            self.local_ports.lock().unwrap().insert(local_addr.port());
+        }
+    }
+
+    /// Datagrams dropped on the sockets since the previous call, if the kernel reports them
+    fn socket_drops(&self) -> Option<u64> {
+        let local_ports = self.local_ports.lock().unwrap();
+        if local_ports.is_empty() {
+            return None;
         }
+        let socket_drops = PROC_NET_UDP_PATHS
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .map(|proc_net_udp| udp_socket_drops(&proc_net_udp, &local_ports))
+            .reduce(u64::saturating_add)?;
+        let last_socket_drops = self.last_socket_drops.swap(socket_drops, Ordering::Relaxed);
+        Some(socket_drops.saturating_sub(last_socket_drops))
     }
 
+    /// Reads the kernel's UDP socket tables, so call it from a metrics thread rather than from
+    /// the receive path
     pub fn report(&self) {
         datapoint_info!(
             self.name,
@@ -80,6 +115,11 @@ impl StreamerReceiveStats {
                 self.packets_count.swap(0, Ordering::Relaxed) as i64,
                 i64
             ),
+            (
+                "bytes_count",
+                self.bytes_count.swap(0, Ordering::Relaxed) as i64,
+                i64
+            ),
             (
                 "packet_batches_count",
                 self.packet_batches_count.swap(0, Ordering::Relaxed) as i64,
@@ -95,10 +135,29 @@ impl StreamerReceiveStats {
                 self.max_channel_len.swap(0, Ordering::Relaxed) as i64,
                 i64
             ),
+            ("socket_drops", self.socket_drops(), Option<i64>),
         );
     }
 }
 
+/// Sums the drops of the sockets bound to any of `ports` in a `/proc/net/udp` table, where each
+/// line after the header looks like:
+/// `sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode ref
+/// pointer drops`
+fn udp_socket_drops(proc_net_udp: &str, ports: &HashSet<u16>) -> u64 {
+    proc_net_udp
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (_, port) = fields.nth(1)?.rsplit_once(':')?;
+            let port = u16::from_str_radix(port, 16).ok()?;
+            let drops = fields.last()?.parse::<u64>().ok()?;
+            ports.contains(&port).then_some(drops)
+        })
+        .fold(0, u64::saturating_add)
+}
+
 pub type Result<T> = std::result::Result<T, StreamerError>;
 
 fn recv_loop(
@@ -135,6 +194,7 @@ fn recv_loop(
                 if len > 0 {
                     let StreamerReceiveStats {
                         packets_count,
+                        bytes_count,
                         packet_batches_count,
                         full_packet_batches_count,
                         max_channel_len,
@@ -142,6 +202,10 @@ fn recv_loop(
                     } = stats;
 
                     packets_count.fetch_add(len, Ordering::Relaxed);
+                    bytes_count.fetch_add(
+                        packet_batch.iter().map(|packet| packet.meta().size).sum(),
+                        Ordering::Relaxed,
+                    );
                     packet_batches_count.fetch_add(1, Ordering::Relaxed);
                     max_channel_len.fetch_max(packet_batch_sender.len(), Ordering::Relaxed);
                     if len == PACKETS_PER_BATCH {
@@ -168,6 +232,7 @@ pub fn receiver(
 ) -> JoinHandle<()> {
     let res = socket.set_read_timeout(Some(Duration::new(1, 0)));
     assert!(res.is_ok(), "streamer::receiver set_read_timeout error");
+    stats.add_socket(&socket);
     Builder::new()
         .name("solReceiver".to_string())
         .spawn(move || {
@@ -519,8 +584,34 @@ mod test {
         exit.store(true, Ordering::Relaxed);
         assert!(stats.packet_batches_count.load(Ordering::Relaxed) >= 1);
         assert_eq!(stats.packets_count.load(Ordering::Relaxed), NUM_PACKETS);
+        assert_eq!(
+            stats.bytes_count.load(Ordering::Relaxed),
+            NUM_PACKETS * PACKET_DATA_SIZE
+        );
         assert_eq!(stats.full_packet_batches_count.load(Ordering::Relaxed), 0);
+        assert_eq!(
+            *stats.local_ports.lock().unwrap(),
+            HashSet::from([addr.port()])
+        );
         t_receiver.join().expect("join");
         t_responder.join().expect("join");
     }
+
+    #[test]
+    fn test_udp_socket_drops() {
+        let proc_net_udp = "\
+   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  310: 00000000:1F40 00000000:0000 07 00000000:00000000 00:00000000 00000000  1000        0 40001 2 0000000000000000 12
+  311: 00000000:1F40 00000000:0000 07 00000000:00000000 00:00000000 00000000  1000        0 40002 2 0000000000000000 3
+  312: 0100007F:1F41 00000000:0000 07 00000000:00000000 00:00000000 00000000  1000        0 40003 2 0000000000000000 100
+  313: 00000000:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000     0        0 40004 2 0000000000000000 7
+";
+        assert_eq!(udp_socket_drops(proc_net_udp, &HashSet::from([8000])), 15);
+        assert_eq!(
+            udp_socket_drops(proc_net_udp, &HashSet::from([8000, 8001])),
+            115
+        );
+        assert_eq!(udp_socket_drops(proc_net_udp, &HashSet::from([9000])), 0);
+        assert_eq!(udp_socket_drops("", &HashSet::from([8000])), 0);
+    }
 }
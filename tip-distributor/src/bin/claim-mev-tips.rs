@@ -56,6 +56,10 @@ struct Args {
     /// The price to pay for priority fee
     #[arg(long, env, default_value_t = 1)]
     micro_lamports: u64,
+
+    /// Vote account of the validator to report unclaimed tips for.
+    #[arg(long, env)]
+    validator_vote_account: Option<Pubkey>,
 }
 
 async fn start_mev_claim_process(
@@ -107,6 +111,7 @@ async fn start_rent_claim(
     should_reclaim_tdas: bool,
     micro_lamports: u64,
     epoch: u64,
+    validator_vote_account: Option<Pubkey>,
 ) -> Result<(), ClaimMevError> {
     let start = Instant::now();
     match reclaim_rent(
@@ -116,6 +121,7 @@ async fn start_rent_claim(
         max_loop_duration,
         should_reclaim_tdas,
         micro_lamports,
+        validator_vote_account,
     )
     .await
     {
@@ -179,6 +185,7 @@ async fn main() -> Result<(), ClaimMevError> {
             args.should_reclaim_tdas,
             args.micro_lamports,
             epoch,
+            args.validator_vote_account,
         )));
     }
     let results = join_all(futs).await;
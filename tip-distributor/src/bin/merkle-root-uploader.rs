@@ -1,5 +1,6 @@
 use {
-    clap::Parser, log::info, solana_sdk::pubkey::Pubkey,
+    clap::Parser, gethostname::gethostname, log::info, solana_metrics::set_host_id,
+    solana_sdk::pubkey::Pubkey,
     solana_tip_distributor::merkle_root_upload_workflow::upload_merkle_root, std::path::PathBuf,
 };
 
@@ -34,17 +35,24 @@ struct Args {
 fn main() {
     env_logger::init();
 
+    gethostname()
+        .into_string()
+        .map(set_host_id)
+        .expect("set hostname");
+
     let args: Args = Args::parse();
 
     info!("starting merkle root uploader...");
-    if let Err(e) = upload_merkle_root(
+    let result = upload_merkle_root(
         &args.merkle_root_path,
         &args.keypair_path,
         &args.rpc_url,
         &args.tip_distribution_program_id,
         args.max_concurrent_rpc_get_reqs,
         args.txn_send_batch_size,
-    ) {
+    );
+    solana_metrics::flush(); // sometimes last datapoint doesn't get emitted. this increases likelihood.
+    if let Err(e) = result {
         panic!("failed to upload merkle roots: {:?}", e);
     }
     info!(
@@ -10,6 +10,7 @@ use {
     },
     log::{error, info},
     solana_client::nonblocking::rpc_client::RpcClient,
+    solana_metrics::{datapoint_error, datapoint_info},
     solana_program::{
         fee_calculator::DEFAULT_TARGET_LAMPORTS_PER_SIGNATURE, native_token::LAMPORTS_PER_SOL,
     },
@@ -19,7 +20,10 @@ use {
         signature::{read_keypair_file, Signer},
         transaction::Transaction,
     },
-    std::{path::PathBuf, time::Duration},
+    std::{
+        path::PathBuf,
+        time::{Duration, Instant},
+    },
     thiserror::Error,
     tokio::runtime::Builder,
 };
@@ -31,6 +35,26 @@ pub enum MerkleRootUploadError {
 
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    AnchorError(anchor_lang::error::Error),
+
+    #[error(transparent)]
+    RpcError(#[from] solana_rpc_client_api::client_error::Error),
+
+    #[error("Expected to have at least {desired_balance} lamports in {payer:?}. Current balance is {start_balance} lamports. Deposit {sol_to_deposit} SOL to continue.")]
+    InsufficientBalance {
+        desired_balance: u64,
+        payer: Pubkey,
+        start_balance: u64,
+        sol_to_deposit: u64,
+    },
+
+    #[error("Not finished with job, transactions left {transactions_left}, failed requests {failed_requests}")]
+    NotFinished {
+        transactions_left: usize,
+        failed_requests: usize,
+    },
 }
 
 pub fn upload_merkle_root(
@@ -42,10 +66,12 @@ pub fn upload_merkle_root(
     txn_send_batch_size: usize,
 ) -> Result<(), MerkleRootUploadError> {
     const MAX_RETRY_DURATION: Duration = Duration::from_secs(600);
+    let start = Instant::now();
 
     let merkle_tree: GeneratedMerkleTreeCollection =
         read_json_from_file(merkle_root_path).expect("read GeneratedMerkleTreeCollection");
     let keypair = read_keypair_file(keypair_path).expect("read keypair file");
+    let epoch = merkle_tree.epoch;
 
     let tip_distribution_config =
         Pubkey::find_program_address(&[Config::SEED], tip_distribution_program_id).0;
@@ -56,7 +82,7 @@ pub fn upload_merkle_root(
         .build()
         .expect("build runtime");
 
-    runtime.block_on(async move {
+    let result = runtime.block_on(async move {
         let rpc_client =
             RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::confirmed());
         let trees: Vec<GeneratedMerkleTree> = merkle_tree
@@ -66,28 +92,32 @@ pub fn upload_merkle_root(
             .collect();
 
         info!("num trees to upload: {:?}", trees.len());
+        let num_trees = trees.len();
 
         // heuristic to make sure we have enough funds to cover execution, assumes all trees need updating 
         {
-            let initial_balance = rpc_client.get_balance(&keypair.pubkey()).await.expect("failed to get balance");
+            let initial_balance = rpc_client.get_balance(&keypair.pubkey()).await?;
             let desired_balance = (trees.len() as u64).checked_mul(DEFAULT_TARGET_LAMPORTS_PER_SIGNATURE).unwrap();
             if initial_balance < desired_balance {
                 let sol_to_deposit = desired_balance.checked_sub(initial_balance).unwrap().checked_add(LAMPORTS_PER_SOL).unwrap().checked_sub(1).unwrap().checked_div(LAMPORTS_PER_SOL).unwrap(); // rounds up to nearest sol
-                panic!("Expected to have at least {} lamports in {}, current balance is {} lamports, deposit {} SOL to continue.",
-                       desired_balance, &keypair.pubkey(), initial_balance, sol_to_deposit)
+                return Err(MerkleRootUploadError::InsufficientBalance {
+                    desired_balance,
+                    payer: keypair.pubkey(),
+                    start_balance: initial_balance,
+                    sol_to_deposit,
+                });
             }
         }
         let mut trees_needing_update: Vec<GeneratedMerkleTree> = vec![];
         for tree in trees {
             let account = rpc_client
                 .get_account(&tree.tip_distribution_account)
-                .await
-                .expect("fetch expect");
+                .await?;
 
             let mut data = account.data.as_slice();
             let fetched_tip_distribution_account =
                 TipDistributionAccount::try_deserialize(&mut data)
-                    .expect("failed to deserialize tip_distribution_account state");
+                    .map_err(MerkleRootUploadError::AnchorError)?;
 
             let needs_upload = match fetched_tip_distribution_account.merkle_root {
                 Some(merkle_root) => {
@@ -130,9 +160,30 @@ pub fn upload_merkle_root(
         let (to_process, failed_transactions) = sign_and_send_transactions_with_retries(
             &keypair, &rpc_client, max_concurrent_rpc_get_reqs, transactions, txn_send_batch_size, MAX_RETRY_DURATION).await;
         if !to_process.is_empty() {
-            panic!("{} remaining mev claim transactions, {} failed requests.", to_process.len(), failed_transactions.len());
+            return Err(MerkleRootUploadError::NotFinished {
+                transactions_left: to_process.len(),
+                failed_requests: failed_transactions.len(),
+            });
         }
+        datapoint_info!(
+            "merkle_root_upload_workflow-upload_completion",
+            ("epoch", epoch, i64),
+            ("trees", num_trees, i64),
+            ("trees_uploaded", trees_needing_update.len(), i64),
+            ("failed_requests", failed_transactions.len(), i64),
+            ("elapsed_us", start.elapsed().as_micros(), i64),
+        );
+        Ok(())
     });
 
-    Ok(())
+    if let Err(e) = &result {
+        datapoint_error!(
+            "merkle_root_upload_workflow-upload_error",
+            ("epoch", epoch, i64),
+            ("error", 1, i64),
+            ("err_str", e.to_string(), String),
+            ("elapsed_us", start.elapsed().as_micros(), i64),
+        );
+    }
+    result
 }
@@ -43,6 +43,8 @@ pub async fn reclaim_rent(
     // Optionally reclaim TipDistributionAccount rents on behalf of validators.
     should_reclaim_tdas: bool,
     micro_lamports: u64,
+    // Optionally report unclaimed tips in this validator's TipDistributionAccounts.
+    validator_vote_account: Option<Pubkey>,
 ) -> Result<(), ClaimMevError> {
     let rpc_client = RpcClient::new_with_timeout_and_commitment(
         rpc_url.clone(),
@@ -62,6 +64,10 @@ pub async fn reclaim_rent(
         Config::try_deserialize(&mut config_account.data.as_slice()).map_err(AnchorError)?;
 
     let epoch = rpc_client.get_epoch_info().await?.epoch;
+    if let Some(validator_vote_account) = validator_vote_account {
+        report_unclaimed_accounts(&accounts, epoch, &validator_vote_account, &signer.pubkey());
+    }
+
     let mut claim_status_pubkeys_to_expire =
         find_expired_claim_status_accounts(&accounts, epoch, signer.pubkey());
     let mut tda_pubkeys_to_expire = find_expired_tda_accounts(&accounts, epoch);
@@ -206,6 +212,68 @@ pub async fn reclaim_rent(
     }
 }
 
+/// Reports how many ClaimStatus accounts paid for by `payer` exist and how many tips in
+/// `validator_vote_account`'s TipDistributionAccounts of past epochs are still unclaimed, as of
+/// the program account scan at the start of a run.
+fn report_unclaimed_accounts(
+    accounts: &[(Pubkey, Account)],
+    epoch: Epoch,
+    validator_vote_account: &Pubkey,
+    payer: &Pubkey,
+) {
+    let mut claim_statuses = 0u64;
+    let mut expired_claim_statuses = 0u64;
+    let mut past_tdas = 0u64;
+    let mut pending_merkle_roots = 0u64;
+    let mut expired_tdas = 0u64;
+    let mut unclaimed_lamports = 0u64;
+    for (_, account) in accounts {
+        if let Ok(claim_status) = ClaimStatus::try_deserialize(&mut account.data.as_slice()) {
+            if claim_status.claim_status_payer != *payer {
+                continue;
+            }
+            claim_statuses += 1;
+            expired_claim_statuses += u64::from(epoch > claim_status.expires_at);
+        } else if let Ok(tda) =
+            TipDistributionAccount::try_deserialize(&mut account.data.as_slice())
+        {
+            if tda.validator_vote_account != *validator_vote_account
+                || tda.epoch_created_at >= epoch
+            {
+                continue;
+            }
+            past_tdas += 1;
+            expired_tdas += u64::from(epoch > tda.expires_at);
+            match tda.merkle_root {
+                Some(merkle_root) => {
+                    unclaimed_lamports = unclaimed_lamports.saturating_add(
+                        merkle_root
+                            .max_total_claim
+                            .saturating_sub(merkle_root.total_funds_claimed),
+                    );
+                }
+                None => pending_merkle_roots += 1,
+            }
+        }
+    }
+
+    datapoint_info!(
+        "claim_mev_workflow-unclaimed_accounts",
+        ("epoch", epoch, i64),
+        (
+            "validator_vote_account",
+            validator_vote_account.to_string(),
+            String
+        ),
+        ("claim_statuses", claim_statuses, i64),
+        ("expired_claim_statuses", expired_claim_statuses, i64),
+        ("past_tdas", past_tdas, i64),
+        ("pending_merkle_roots", pending_merkle_roots, i64),
+        ("expired_tdas", expired_tdas, i64),
+        ("unclaimed_lamports", unclaimed_lamports, i64),
+    );
+}
+
 fn find_expired_claim_status_accounts(
     accounts: &[(Pubkey, Account)],
     epoch: Epoch,
@@ -805,6 +805,26 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .help("A snapshot hash must be published in gossip by this validator to be accepted. \
                        May be specified multiple times. If unspecified any snapshot hash will be accepted"),
         )
+        .arg(
+            Arg::with_name("watched_accounts")
+                .long("watch-account")
+                .validator(is_pubkey)
+                .value_name("ADDRESS")
+                .multiple(true)
+                .takes_value(true)
+                .help("Periodically report the lamports, owner and data size of this account, \
+                       and count changes to its owner or data size. May be specified multiple times"),
+        )
+        .arg(
+            Arg::with_name("watched_programs")
+                .long("watch-program")
+                .validator(is_pubkey)
+                .value_name("PROGRAM ID")
+                .multiple(true)
+                .takes_value(true)
+                .help("Report the slot this upgradeable program was last deployed at, and count \
+                       changes to its program data. May be specified multiple times"),
+        )
         .arg(
             Arg::with_name("debug_key")
                 .long("debug-key")
@@ -1847,7 +1867,10 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                         .index(1)
                         .help("New filter using the same format as the RUST_LOG environment variable")
                 )
-                .after_help("Note: the new filter only applies to the currently running validator instance")
+                .after_help("Note: the new filter only applies to the currently running validator instance. \
+                             Debug level also enables expensive metrics collectors for the module, \
+                             e.g. solana_runtime::prioritization_fee_cache=debug reports the \
+                             priority fees of our leader blocks by program")
         )
         .subcommand(
             SubCommand::with_name("staked-nodes-overrides")
@@ -1173,6 +1173,20 @@ pub fn main() {
         None
     };
 
+    let watched_accounts: HashSet<_> = if matches.is_present("watched_accounts") {
+        values_t_or_exit!(matches, "watched_accounts", Pubkey)
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::default()
+    };
+    let watched_programs: HashSet<_> = if matches.is_present("watched_programs") {
+        values_t_or_exit!(matches, "watched_programs", Pubkey)
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::default()
+    };
     let known_validators = validators_set(
         &identity_keypair.pubkey(),
         &matches,
@@ -1553,6 +1567,8 @@ pub fn main() {
         repair_validators,
         repair_whitelist,
         gossip_validators,
+        watched_accounts,
+        watched_programs,
         wal_recovery_mode,
         run_verification: !(matches.is_present("skip_poh_verify")
             || matches.is_present("skip_startup_ledger_verification")),